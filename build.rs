@@ -0,0 +1,73 @@
+/// Generates the solution registry of the main binary.
+/// Every `src/bin/<year>/<day>.rs` is included as a module and the `SOLUTION` constant
+/// defined by its `solution!` invocation is collected into `SOLUTIONS`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn find_solutions(bin_dir: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut solutions = vec![];
+
+    let Ok(years) = fs::read_dir(bin_dir) else {
+        return solutions;
+    };
+
+    for year_dir in years.flatten() {
+        let Some(year) = year_dir.file_name().to_str().and_then(|s| s.parse().ok()) else {
+            continue;
+        };
+
+        let Ok(days) = fs::read_dir(year_dir.path()) else {
+            continue;
+        };
+
+        for day_file in days.flatten() {
+            let path = day_file.path();
+
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                if let Some(day) = path.file_stem().and_then(|s| s.to_str()?.parse().ok()) {
+                    solutions.push((year, day, path));
+                }
+            }
+        }
+    }
+
+    solutions.sort();
+    solutions
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let solutions = find_solutions(&Path::new(&manifest_dir).join("src").join("bin"));
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day, path) in solutions {
+        let module = format!("y{year}_d{day:02}");
+        // solution tests already run as part of their own binary, which also reports their lints.
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(clippy::all, clippy::pedantic, unused)]\n#[path = {path:?}]\nmod {module};\n"
+        ));
+        entries.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+
+    let registry = format!(
+        "{modules}
+/// Every solution in `src/bin`, ordered by puzzle.
+#[cfg(not(test))]
+pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[
+{entries}];
+
+#[cfg(test)]
+pub const SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];
+"
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
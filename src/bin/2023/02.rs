@@ -1,6 +1,6 @@
 use lazy_static::lazy_static;
 use regex::Regex;
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 struct CubeDraw {
     red: u32,
@@ -90,13 +90,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2286));
     }
 }
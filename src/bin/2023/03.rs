@@ -6,7 +6,7 @@ use advent_of_code::utils::grid::{
     find_intersections, has_intersections, Cellular, GridCell, Growable,
 };
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 #[derive(Debug)]
 struct NumberCell {
@@ -137,13 +137,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(467835));
     }
 
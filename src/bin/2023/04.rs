@@ -3,7 +3,7 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use regex::Regex;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 struct Card {
     winning_numbers: Vec<u32>,
@@ -77,13 +77,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(30));
     }
 }
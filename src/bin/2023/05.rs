@@ -7,7 +7,7 @@ use itertools::{Itertools, MinMaxResult};
 
 use advent_of_code::utils::parsing::get_big_numbers;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5, parse);
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
 struct RangeMap {
@@ -21,7 +21,7 @@ struct Mapper {
     ranges: Vec<RangeMap>,
 }
 
-struct Inputs {
+pub struct Inputs {
     seeds: Vec<u64>,
     mappers: Vec<Mapper>,
 }
@@ -213,9 +213,7 @@ fn compile(mappers: &Vec<Mapper>) -> Mapper {
     Mapper { ranges }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let inputs = parse(input);
-
+pub fn part_one(inputs: &Inputs) -> Option<u64> {
     inputs
         .seeds
         .iter()
@@ -223,9 +221,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         .min()
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let inputs = parse(input);
-
+pub fn part_two(inputs: &Inputs) -> Option<u64> {
     inputs
         .seeds
         .chunks_exact(2)
@@ -242,19 +238,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_compile() {
-        let inputs = parse(&advent_of_code::template::read_file("examples", DAY));
+        let inputs = parse(&advent_of_code::template::read_file("examples", PUZZLE));
         let mappers = compile(&inputs.mappers);
         println!("{:?}", mappers)
     }
@@ -2,7 +2,7 @@ use itertools::Itertools;
 
 use advent_of_code::utils::parsing::get_big_numbers;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 fn parse(input: &str) -> Vec<(u64, u64)> {
     let parts = input.split("\n").collect_vec();
@@ -67,13 +67,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(288));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert_eq!(result, Some(71503));
     }
 }
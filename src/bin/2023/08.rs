@@ -5,7 +5,7 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use regex::Regex;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 enum Direction {
     Left,
@@ -155,13 +155,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 }
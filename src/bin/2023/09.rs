@@ -4,7 +4,7 @@ use rayon::prelude::*;
 
 use advent_of_code::utils::parsing::get_big_signed_numbers;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 fn get_differences(numbers: &Vec<i64>) -> Vec<i64> {
     numbers
@@ -77,13 +77,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 }
@@ -4,7 +4,7 @@ use advent_of_code::utils::dense_grid::{DenseGrid, DOWN, LEFT, RIGHT, UP};
 use advent_of_code::utils::geometry;
 use advent_of_code::utils::geometry::{Direction, XY};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 enum Pipe {
@@ -125,19 +125,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(80));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(10));
     }
 
     #[test]
     fn plot() {
-        let input = advent_of_code::template::read_file("inputs", DAY);
+        let input = advent_of_code::template::read_file("inputs", PUZZLE);
         let grid = DenseGrid::parse(&input, parse_pipe, Some(Pipe::Empty));
         let Some((_, start_xy)) = grid.find(|pipe| *pipe == Pipe::Start) else {
             panic!()
@@ -2,7 +2,7 @@ use itertools::Itertools;
 
 use advent_of_code::utils::geometry::XY;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 struct Galaxy {
     pos: XY,
@@ -110,13 +110,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(374));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(8410));
     }
 }
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use itertools::Itertools;
 use rayon::prelude::*;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12);
 
 #[derive(PartialEq, Debug, Copy, Clone)]
 enum RepairStatus {
@@ -140,13 +140,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(525152));
     }
 }
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use rayon::prelude::*;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
 #[derive(Eq, PartialEq, Debug)]
 enum Tile {
@@ -202,13 +202,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(400));
     }
 }
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 
 use advent_of_code::utils::dense_grid::DenseGrid;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
 #[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
 enum ReflectorTile {
@@ -176,13 +176,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(136));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(64));
     }
 }
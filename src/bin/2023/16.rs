@@ -6,7 +6,7 @@ use rayon::prelude::*;
 use advent_of_code::utils::dense_grid::{DenseGrid, DOWN, LEFT, RIGHT, UP};
 use advent_of_code::utils::geometry::XY;
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2023, 16);
 
 #[derive(Eq, PartialEq, Hash, Debug)]
 struct Splitter {
@@ -283,13 +283,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(51));
     }
 }
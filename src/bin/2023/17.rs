@@ -6,7 +6,7 @@ use pathfinding::prelude::astar;
 use advent_of_code::utils::dense_grid::{DenseGrid, DOWN, LEFT, RIGHT, UP};
 use advent_of_code::utils::geometry::XY;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2023, 17);
 
 #[derive(Eq, PartialEq, Hash, Copy, Clone)]
 struct CrucibleConfig {
@@ -198,13 +198,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(102));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(94));
     }
 
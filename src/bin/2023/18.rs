@@ -7,7 +7,7 @@ use regex::Regex;
 use advent_of_code::utils::dense_grid::{DOWN, LEFT, ORIGIN, RIGHT, UP};
 use advent_of_code::utils::geometry::{shoelace_area, XY};
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2023, 18);
 
 #[derive(Debug)]
 struct Move {
@@ -105,13 +105,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(62));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(952408144115));
     }
 
@@ -7,7 +7,7 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19);
 
 lazy_static! {
     static ref WORKFLOW_RE: Regex = Regex::new(r"^(?P<id>\w+)\{(?P<rules>.*)}$").unwrap();
@@ -343,13 +343,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(167409079868000));
     }
 }
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use itertools::Itertools;
 use num::integer::lcm;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum SignalLevel {
@@ -458,13 +458,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(11687500));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -6,7 +6,7 @@ use pathfinding::prelude::dijkstra_all;
 use advent_of_code::utils::dense_grid::DenseGrid;
 use advent_of_code::utils::geometry::XY;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21);
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum Tile {
@@ -146,13 +146,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(16));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(16733044));
     }
 }
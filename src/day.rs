@@ -80,6 +80,116 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// A year in which advent of code took place (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The first year advent of code took place.
+    pub const FIRST: u16 = 2015;
+
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(Self::FIRST..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the default [`Year`] from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year number of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a single puzzle, i.e. a [`Day`] of a given [`Year`].
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -130,11 +240,27 @@ macro_rules! day {
     }};
 }
 
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= $crate::Year::FIRST && $year <= 9999,
+            concat!(
+                "invalid year number `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, PuzzleId, Year};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +293,19 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn year_from_str() {
+        assert_eq!("2023".parse::<Year>().ok(), Some(Year(2023)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn puzzle_id_display() {
+        let puzzle = PuzzleId::new(Year(2022), Day(5));
+        assert_eq!(puzzle.to_string(), "2022-05");
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,92 +1,554 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
-use args::{parse, AppArguments};
+use advent_of_code::template::commands::{
+    all, bench_compare, download, read, scaffold, solve, stars, vault, watch,
+};
+use args::{parse, print_completions, AppArguments};
+
+/// Solutions compiled into this binary, see `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
 
 mod args {
-    use std::process;
+    use std::error::Error;
+    use std::io::{self, Write};
+    use std::num::NonZeroUsize;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use clap::builder::{PossibleValuesParser, TypedValueParser};
+    use clap::{value_parser, Args, CommandFactory, Parser, Subcommand, ValueEnum};
+    use clap_complete::Shell;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::all::{AllOptions, Timeouts};
+    use advent_of_code::template::commands::scaffold::ScaffoldOptions;
+    use advent_of_code::template::commands::vault::Action;
+    use advent_of_code::template::config::{self, Config, CONFIG_PATH};
+    use advent_of_code::template::report::Format;
+    use advent_of_code::template::runner::{
+        BenchOptions, InputSource, RunOptions, Statistic, Submit,
+    };
+    use advent_of_code::{Day, PuzzleId, Year};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
+            force: bool,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+            part: Option<u8>,
+            refresh: bool,
+            pager: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            options: ScaffoldOptions,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
-            time: bool,
-            submit: Option<u8>,
+            options: RunOptions,
+            input: InputSource,
+            watch: bool,
         },
         All {
-            release: bool,
-            time: bool,
+            year: Year,
+            options: RunOptions,
+            all_options: AllOptions,
+        },
+        Vault {
+            year: Year,
+            action: Action,
+        },
+        Stars {
+            year: Year,
+            online: bool,
+            leaderboard: Option<String>,
+        },
+        BenchCompare {
+            rev: Option<String>,
+            threshold: f64,
+            statistic: Statistic,
+        },
+        Completions {
+            shell: Shell,
         },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    /// Scaffold, run and benchmark Advent of Code solutions.
+    ///
+    /// Defaults for the year, `--release`, `--time`, `--format` and the data directory can be set in
+    /// `aoc.toml`.
+    #[derive(Parser)]
+    #[command(name = "advent_of_code", version, arg_required_else_help = true)]
+    pub struct Cli {
+        #[command(subcommand)]
+        command: Command,
+    }
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
-            },
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
-            },
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
+    #[derive(Subcommand)]
+    enum Command {
+        /// Create the module, input and example files of a day.
+        Scaffold {
+            #[command(flatten)]
+            puzzle: PuzzleArgs,
+            /// The return type of both parts.
+            #[arg(long = "type", value_name = "TYPE", default_value = "u32")]
+            answer_type: String,
+            /// Parse the input into a `DenseGrid<char>` that both parts share.
+            #[arg(long)]
+            grid: bool,
+            /// Add a regex for parsing lines.
+            #[arg(long)]
+            regex: bool,
+            /// Download the puzzle and pre-fill the example and its expected answers.
+            #[arg(long)]
+            fetch: bool,
+            /// Overwrite existing files with new contents. Inputs are never overwritten.
+            #[arg(long)]
+            force: bool,
+            /// Only show what would change.
+            #[arg(long)]
+            dry_run: bool,
+        },
+        /// Download the input and description of a day.
+        Download {
+            #[command(flatten)]
+            puzzle: PuzzleArgs,
+            /// Download the input again, even if it was already downloaded or modified.
+            #[arg(long)]
+            force: bool,
+        },
+        /// Show the description of a day in the terminal.
+        Read {
+            #[command(flatten)]
+            puzzle: PuzzleArgs,
+            /// Only show one part.
+            #[arg(long, value_parser = value_parser!(u8).range(1..=2))]
+            part: Option<u8>,
+            /// Download the description again, e.g. to show part two after solving part one.
+            #[arg(long)]
+            refresh: bool,
+            /// Print the description instead of showing it in `$PAGER`.
+            #[arg(long)]
+            no_pager: bool,
+        },
+        /// Run the solution of a day.
+        Solve {
+            #[command(flatten)]
+            puzzle: PuzzleArgs,
+            #[command(flatten)]
+            run: RunArgs,
+            #[command(flatten)]
+            input: InputArgs,
+            /// Submit an answer after running: a part, or `auto` for the last part with a real answer.
+            #[arg(
+                long,
+                value_parser = one_of::<Submit>(&["1", "2", "auto"]),
+                conflicts_with_all = ["input", "example", "examples", "stdin"],
+            )]
+            submit: Option<Submit>,
+            /// Run the solution again whenever its module, `src/utils` or its data change.
+            #[arg(long, conflicts_with_all = ["submit", "stdin"])]
+            watch: bool,
+        },
+        /// Run the solutions of every day of a year.
+        All {
+            #[command(flatten)]
+            year: YearArgs,
+            #[command(flatten)]
+            run: RunArgs,
+            /// Exit with a non-zero status if a part returns an incorrect answer or fails to run.
+            #[arg(long)]
+            verify: bool,
+            #[command(flatten)]
+            stat: StatArgs,
+            /// Fail a day that runs longer than this many seconds.
+            #[arg(long, value_name = "SECONDS")]
+            timeout: Option<u64>,
+            /// Fail a part that runs longer than this many seconds.
+            #[arg(long, value_name = "SECONDS")]
+            part_timeout: Option<u64>,
+            /// How many days run at the same time. Defaults to one per CPU, or to one with `--time`.
+            #[arg(long)]
+            jobs: Option<NonZeroUsize>,
+        },
+        /// Compare the latest benchmarks against an earlier run.
+        BenchCompare {
+            /// The revision to compare against. Defaults to the previous run.
+            rev: Option<String>,
+            /// Flag parts that got slower by more than this many percent.
+            #[arg(long, default_value_t = 10.0)]
+            threshold: f64,
+            #[command(flatten)]
+            stat: StatArgs,
+        },
+        /// Show the stars of a year.
+        Stars {
+            #[command(flatten)]
+            year: YearArgs,
+            /// Also show the stars on adventofcode.com.
+            #[arg(long)]
+            online: bool,
+            /// Rank a private leaderboard, given as its id or as a downloaded JSON file.
+            #[arg(long, value_name = "ID_OR_FILE")]
+            leaderboard: Option<String>,
+        },
+        /// Lock or unlock the inputs of a year in the encrypted vault.
+        Vault {
+            #[command(flatten)]
+            year: YearArgs,
+            action: VaultAction,
+        },
+        /// Print the completions of this binary for a shell.
+        Completions { shell: Shell },
+    }
+
+    #[derive(Args)]
+    struct YearArgs {
+        /// The year of the puzzles. Defaults to `AOC_YEAR` or the `year` of `aoc.toml`.
+        #[arg(long)]
+        year: Option<Year>,
+    }
+
+    #[derive(Args)]
+    struct PuzzleArgs {
+        /// The day of the puzzle, e.g. `5`.
+        day: Day,
+        #[command(flatten)]
+        year: YearArgs,
+    }
+
+    /// The options that are shared by `solve` and `all`.
+    #[derive(Args)]
+    struct RunArgs {
+        /// Run an optimized build. With `all --time`, also update the README and benchmark history.
+        #[arg(long, overrides_with = "no_release")]
+        release: bool,
+        /// Run an unoptimized build, even if `aoc.toml` sets `release`.
+        #[arg(long, overrides_with = "release")]
+        no_release: bool,
+        /// Benchmark every part.
+        #[arg(long, overrides_with = "no_time")]
+        time: bool,
+        /// Run every part once, even if `aoc.toml` sets `time`.
+        #[arg(long, overrides_with = "time")]
+        no_time: bool,
+        /// The output format of the results.
+        #[arg(long, value_parser = one_of::<Format>(&["text", "json", "csv"]))]
+        format: Option<Format>,
+        /// How long a benchmarked part runs before samples are taken, in milliseconds.
+        #[arg(long, value_name = "MS")]
+        warmup: Option<u64>,
+        /// How long samples of a benchmarked part are taken for, in milliseconds.
+        #[arg(long, value_name = "MS")]
+        budget: Option<u64>,
+    }
+
+    /// The input that `solve` runs against. Defaults to the puzzle input.
+    #[derive(Args)]
+    #[group(multiple = false)]
+    struct InputArgs {
+        /// Run against another input file.
+        #[arg(long, value_name = "PATH")]
+        input: Option<String>,
+        /// Run against the example of the day.
+        #[arg(long)]
+        example: bool,
+        /// Run against every named example and check their expected answers.
+        #[arg(long)]
+        examples: bool,
+        /// Read the input from the standard input.
+        #[arg(long)]
+        stdin: bool,
+    }
+
+    /// What `vault` does with the inputs.
+    #[derive(Clone, Copy, ValueEnum)]
+    enum VaultAction {
+        /// Encrypt the plain text inputs and remove them.
+        Lock,
+        /// Decrypt the locked inputs to plain text files.
+        Unlock,
+    }
+
+    #[derive(Args)]
+    struct StatArgs {
+        /// The statistic that summarizes the samples of a benchmarked part.
+        #[arg(long, value_parser = one_of::<Statistic>(&["mean", "median"]))]
+        stat: Option<Statistic>,
+    }
+
+    /// Parses a value with [`FromStr`], listing the possible values in the help and completions.
+    fn one_of<T>(values: &'static [&'static str]) -> impl TypedValueParser<Value = T>
+    where
+        T: FromStr + Clone + Send + Sync + 'static,
+        T::Err: Error + Send + Sync + 'static,
+    {
+        PossibleValuesParser::new(values).try_map(|value| value.parse())
+    }
+
+    /// Resolves a flag that can be turned on and off, falling back to the project config.
+    fn flag(on: bool, off: bool, config: Option<bool>) -> bool {
+        on || (!off && config.unwrap_or(false))
+    }
+
+    impl YearArgs {
+        /// Falls back to the `AOC_YEAR` environment variable, then to the project config.
+        fn resolve(&self, config: &Config) -> Result<Year, Box<dyn Error>> {
+            self.year
+                .or_else(Year::from_env)
+                .or(config.year)
+                .ok_or_else(|| {
+                    format!(
+                        "no year specified, pass `--year`, set `AOC_YEAR` or set `year` in `{CONFIG_PATH}`."
+                    )
+                    .into()
+                })
+        }
+    }
+
+    impl PuzzleArgs {
+        fn resolve(&self, config: &Config) -> Result<PuzzleId, Box<dyn Error>> {
+            Ok(PuzzleId::new(self.year.resolve(config)?, self.day))
+        }
+    }
+
+    impl RunArgs {
+        fn is_release(&self, config: &Config) -> bool {
+            flag(self.release, self.no_release, config.release)
+        }
+
+        fn resolve(&self, submit: Option<Submit>, config: &Config) -> RunOptions {
+            let defaults = BenchOptions::default();
+
+            RunOptions {
+                is_timed: flag(self.time, self.no_time, config.time),
+                submit,
+                format: self.format.or(config.format).unwrap_or_default(),
+                bench: BenchOptions {
+                    warmup: self.warmup.map_or(defaults.warmup, Duration::from_millis),
+                    budget: self.budget.map_or(defaults.budget, Duration::from_millis),
+                },
+                skip_verify: false,
             }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
+        }
+    }
+
+    impl InputArgs {
+        fn resolve(self) -> InputSource {
+            match self {
+                InputArgs {
+                    input: Some(path), ..
+                } => InputSource::File(path),
+                InputArgs { example: true, .. } => InputSource::Example,
+                InputArgs { examples: true, .. } => InputSource::Examples,
+                InputArgs { stdin: true, .. } => InputSource::Stdin,
+                _ => InputSource::Puzzle,
             }
-        };
+        }
+    }
 
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+    impl From<VaultAction> for Action {
+        fn from(action: VaultAction) -> Self {
+            match action {
+                VaultAction::Lock => Action::Lock,
+                VaultAction::Unlock => Action::Unlock,
+            }
         }
+    }
+
+    /// Prints the completions of this binary for a shell.
+    pub fn print_completions(shell: Shell) {
+        let mut command = Cli::command();
+        let name = command.get_name().to_string();
+        let mut completions = vec![];
+        clap_complete::generate(shell, &mut command, name, &mut completions);
+        // e.g. piping into `head` closes stdout early, which is not an error.
+        let _ = io::stdout().write_all(&completions);
+    }
+
+    /// Parses the command line, exiting with a usage message if it is invalid. Options that are not
+    /// passed fall back to the project config, which is only read by the commands that use it.
+    pub fn parse() -> Result<AppArguments, Box<dyn Error>> {
+        // only the commands that use the project config read it, so a broken config does not affect
+        // the others, e.g. `completions`.
+        let app_args = match Cli::parse().command {
+            Command::Scaffold {
+                puzzle,
+                answer_type,
+                grid,
+                regex,
+                fetch,
+                force,
+                dry_run,
+            } => {
+                let config = config::get()?;
+                AppArguments::Scaffold {
+                    puzzle: puzzle.resolve(config)?,
+                    options: ScaffoldOptions {
+                        answer_type,
+                        grid,
+                        regex,
+                        fetch,
+                        force,
+                        dry_run,
+                    },
+                }
+            }
+            Command::Download { puzzle, force } => {
+                let config = config::get()?;
+                AppArguments::Download {
+                    puzzle: puzzle.resolve(config)?,
+                    force,
+                }
+            }
+            Command::Read {
+                puzzle,
+                part,
+                refresh,
+                no_pager,
+            } => {
+                let config = config::get()?;
+                AppArguments::Read {
+                    puzzle: puzzle.resolve(config)?,
+                    part,
+                    refresh,
+                    pager: !no_pager,
+                }
+            }
+            Command::Solve {
+                puzzle,
+                run,
+                input,
+                submit,
+                watch,
+            } => {
+                let config = config::get()?;
+                AppArguments::Solve {
+                    puzzle: puzzle.resolve(config)?,
+                    release: run.is_release(config),
+                    options: run.resolve(submit, config),
+                    input: input.resolve(),
+                    watch,
+                }
+            }
+            Command::All {
+                year,
+                run,
+                verify,
+                stat,
+                timeout,
+                part_timeout,
+                jobs,
+            } => {
+                let config = config::get()?;
+                AppArguments::All {
+                    year: year.resolve(config)?,
+                    options: run.resolve(None, config),
+                    all_options: AllOptions {
+                        is_release: run.is_release(config),
+                        is_verify: verify,
+                        statistic: stat.stat.unwrap_or_default(),
+                        timeouts: Timeouts {
+                            day: timeout.map(Duration::from_secs),
+                            part: part_timeout.map(Duration::from_secs),
+                        },
+                        jobs: jobs.map(NonZeroUsize::get),
+                    },
+                }
+            }
+            Command::BenchCompare {
+                rev,
+                threshold,
+                stat,
+            } => AppArguments::BenchCompare {
+                rev,
+                threshold,
+                statistic: stat.stat.unwrap_or_default(),
+            },
+            Command::Stars {
+                year,
+                online,
+                leaderboard,
+            } => {
+                let config = config::get()?;
+                AppArguments::Stars {
+                    year: year.resolve(config)?,
+                    online,
+                    leaderboard,
+                }
+            }
+            Command::Vault { year, action } => {
+                let config = config::get()?;
+                AppArguments::Vault {
+                    year: year.resolve(config)?,
+                    action: action.into(),
+                }
+            }
+            Command::Completions { shell } => AppArguments::Completions { shell },
+        };
 
         Ok(app_args)
     }
 }
 
 fn main() {
-    match parse() {
+    let args = match parse() {
+        Ok(args) => args,
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
-        },
     };
+
+    let result = match args {
+        AppArguments::All {
+            year,
+            options,
+            all_options,
+        } => all::handle(solutions::SOLUTIONS, year, &options, &all_options),
+        AppArguments::BenchCompare {
+            rev,
+            threshold,
+            statistic,
+        } => bench_compare::handle(rev.as_deref(), threshold, statistic),
+        AppArguments::Stars {
+            year,
+            online,
+            leaderboard,
+        } => stars::handle(year, online, leaderboard.as_deref()),
+        AppArguments::Vault { year, action } => vault::handle(year, action),
+        AppArguments::Download { puzzle, force } => download::handle(puzzle, force),
+        AppArguments::Read {
+            puzzle,
+            part,
+            refresh,
+            pager,
+        } => read::handle(puzzle, part, refresh, pager),
+        AppArguments::Scaffold { puzzle, options } => scaffold::handle(puzzle, &options),
+        AppArguments::Solve {
+            puzzle,
+            release,
+            options,
+            input,
+            watch: false,
+        } => solve::handle(puzzle, release, &options, &input),
+        AppArguments::Solve {
+            puzzle,
+            release,
+            options,
+            input,
+            watch: true,
+        } => watch::handle(puzzle, release, &options, &input),
+        AppArguments::Completions { shell } => {
+            print_completions(shell);
+            Ok(())
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,267 @@
+/// Module with the answers that the parts of a solution return, see [`ToAnswer`].
+///
+/// Most puzzles ask for a number or a string. A few draw block letters instead, e.g. on the screen of
+/// 2016 day 8 or the CRT of 2022 day 10. Parts can return these drawings directly, as a multi-line
+/// string or as a grid, and they are read with [`ocr`] before they are verified and submitted.
+use std::fmt::Display;
+
+use crate::utils::dense_grid::DenseGrid;
+
+/// The answer of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture of several lines, e.g. block letters.
+    Drawing(String),
+}
+
+impl Answer {
+    /// Returns the answer as it is recorded, verified and submitted. Drawings are read with [`ocr`],
+    /// a drawing that can not be read is kept as it is.
+    #[must_use]
+    pub fn submission(&self) -> String {
+        match self {
+            Answer::Integer(n) => n.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::Drawing(drawing) => ocr(drawing).unwrap_or_else(|| drawing.clone()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Drawing(drawing) => f.write_str(drawing.trim_end_matches('\n')),
+        }
+    }
+}
+
+/// A value that a part can return, see [`run_part`](crate::template::runner::run_part). Other types
+/// that implement [`Display`] can be returned as [`Displayed`].
+pub trait ToAnswer {
+    fn to_answer(&self) -> Answer;
+}
+
+/// Answers a part with the [`Display`] output of a value, e.g. `Some(Displayed(point))`. Output of
+/// several lines is a drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Displayed<T>(pub T);
+
+impl<T: Display> ToAnswer for Displayed<T> {
+    fn to_answer(&self) -> Answer {
+        self.0.to_string().to_answer()
+    }
+}
+
+macro_rules! impl_to_answer_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl ToAnswer for $t {
+                fn to_answer(&self) -> Answer {
+                    i128::try_from(*self).map_or_else(|_| Answer::Text(self.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_answer_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl ToAnswer for str {
+    fn to_answer(&self) -> Answer {
+        if self.trim_end_matches('\n').contains('\n') {
+            Answer::Drawing(self.to_string())
+        } else {
+            Answer::Text(self.to_string())
+        }
+    }
+}
+
+impl ToAnswer for String {
+    fn to_answer(&self) -> Answer {
+        self.as_str().to_answer()
+    }
+}
+
+impl ToAnswer for char {
+    fn to_answer(&self) -> Answer {
+        Answer::Text(self.to_string())
+    }
+}
+
+impl ToAnswer for Answer {
+    fn to_answer(&self) -> Answer {
+        self.clone()
+    }
+}
+
+impl<T: ToAnswer + ?Sized> ToAnswer for &T {
+    fn to_answer(&self) -> Answer {
+        (**self).to_answer()
+    }
+}
+
+impl ToAnswer for DenseGrid<char> {
+    fn to_answer(&self) -> Answer {
+        Answer::Drawing(self.to_string())
+    }
+}
+
+/// Lit cells are drawn as `#`, the others as `.`.
+impl ToAnswer for DenseGrid<bool> {
+    fn to_answer(&self) -> Answer {
+        let drawing = self
+            .rows_iter()
+            .map(|row| {
+                let mut line: String = row.iter().map(|&lit| if lit { '#' } else { '.' }).collect();
+                line.push('\n');
+                line
+            })
+            .collect();
+
+        Answer::Drawing(drawing)
+    }
+}
+
+/// The letters of the font that the puzzles draw, six pixels high and separated by empty columns.
+const LETTERS: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+fn is_lit(pixel: char) -> bool {
+    !pixel.is_whitespace() && pixel != '.'
+}
+
+/// Reads the block letters of a drawing, e.g. `EHZRUFPK`. Any character but `.` and whitespace is a
+/// lit pixel, so both `#` and `█` work. Returns [`None`] if the drawing is not six pixels high or
+/// contains an unknown letter.
+#[must_use]
+pub fn ocr(drawing: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = drawing
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect::<Vec<bool>>())
+        .skip_while(|row| !row.contains(&true))
+        .collect();
+
+    let height = rows.iter().rposition(|row| row.contains(&true))? + 1;
+    let rows = &rows[..height];
+
+    if height != 6 {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let is_lit_at = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let is_empty_column = |x: usize| (0..height).all(|y| !is_lit_at(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let end = (x..width).find(|&x| is_empty_column(x)).unwrap_or(width);
+        let glyph = (0..height)
+            .map(|y| {
+                (x..end)
+                    .map(|x| if is_lit_at(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let (letter, _) = LETTERS.iter().find(|(_, letter)| *letter == glyph)?;
+        letters.push(*letter);
+        x = end;
+    }
+
+    Some(letters)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ocr, Answer, Displayed, ToAnswer, LETTERS};
+    use crate::utils::dense_grid::DenseGrid;
+
+    /// Draws letters of the font next to each other, as the puzzles do.
+    fn draw(text: &str, lit: char, unlit: char) -> String {
+        let glyphs: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                let (_, glyph) = LETTERS.iter().find(|(letter, _)| *letter == c).unwrap();
+                glyph.lines().collect()
+            })
+            .collect();
+
+        (0..6)
+            .map(|y| {
+                let row: Vec<&str> = glyphs.iter().map(|glyph| glyph[y]).collect();
+                format!("{}\n", row.join("."))
+            })
+            .collect::<String>()
+            .replace('#', &lit.to_string())
+            .replace('.', &unlit.to_string())
+    }
+
+    #[test]
+    fn converts_values() {
+        assert_eq!(142_u32.to_answer(), Answer::Integer(142));
+        assert_eq!((-7_i64).to_answer(), Answer::Integer(-7));
+        assert_eq!(u128::MAX.to_answer(), Answer::Text(u128::MAX.to_string()));
+        assert_eq!("abc".to_answer(), Answer::Text("abc".into()));
+        assert_eq!(Displayed(1.5).to_answer(), Answer::Text("1.5".into()));
+        assert_eq!(
+            String::from("#.\n.#\n").to_answer(),
+            Answer::Drawing("#.\n.#\n".into())
+        );
+    }
+
+    #[test]
+    fn reads_block_letters() {
+        let letters: String = LETTERS.iter().map(|(letter, _)| letter).collect();
+
+        assert_eq!(ocr(&draw(&letters, '#', '.')), Some(letters));
+        assert_eq!(
+            ocr(&format!("\n{}\n", draw("HI", '█', ' '))).as_deref(),
+            Some("HI")
+        );
+        assert_eq!(ocr("#..#\n####\n#..#\n"), None);
+        assert_eq!(ocr(&draw("HI", '#', '.').replace("###", "##.")), None);
+    }
+
+    #[test]
+    fn submits_drawings_as_letters() {
+        let drawing = draw("EZ", '#', '.');
+        let grid = DenseGrid::parse(drawing.trim_end(), |c| c == '#', None);
+
+        assert_eq!(grid.to_answer(), Answer::Drawing(drawing.clone()));
+        assert_eq!(grid.to_answer().submission(), "EZ");
+        assert_eq!(
+            Answer::Drawing("#\n".repeat(7)).submission(),
+            "#\n".repeat(7)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+/// Module that reads the expected answers of a puzzle and verifies results against them.
+/// Answers live in `data/<year>/answers/<day>.toml`, e.g.:
+///
+/// ```toml
+/// part1 = 142
+/// part2 = "281"
+/// ```
+use std::{fs, io};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse recorded answers: {e}"),
+            Error::IO(e) => write!(f, "could not read recorded answers: {e}"),
+        }
+    }
+}
+
+/// The recorded answers of a puzzle. Parts without a recorded answer are [`None`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// The outcome of comparing an answer against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+#[must_use]
+pub fn get_answers_path(puzzle: PuzzleId) -> String {
+    get_data_path("answers", puzzle, "toml")
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let table: toml::Table = s.parse().map_err(|e| Error::Parser(format!("{e}")))?;
+
+        let get = |key: &str| -> Result<Option<String>, Error> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+                Some(toml::Value::Integer(i)) => Ok(Some(i.to_string())),
+                Some(_) => Err(Error::Parser(format!(
+                    "`{key}` should be a string or an integer."
+                ))),
+            }
+        };
+
+        Ok(Answers {
+            part_1: get("part1")?,
+            part_2: get("part2")?,
+        })
+    }
+
+    /// Reads the answers of a puzzle. A missing answers file means no answers were recorded yet.
+    pub fn read(puzzle: PuzzleId) -> Result<Self, Error> {
+        match fs::read_to_string(get_answers_path(puzzle)) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn verify(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected.trim_end() == answer.trim_end() => {
+                Verdict::Correct
+            }
+            (Some(expected), _) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_strings_and_integers() {
+        let answers = Answers::parse("part1 = 142\npart2 = \"abc\"\n").unwrap();
+        assert_eq!(answers.part_1.as_deref(), Some("142"));
+        assert_eq!(answers.part_2.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn parses_missing_parts() {
+        let answers = Answers::parse("part1 = 142\n").unwrap();
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_unsupported_values() {
+        Answers::parse("part1 = [1, 2]\n").unwrap();
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::parse("part1 = 142\n").unwrap();
+        assert_eq!(answers.verify(1, Some("142")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, Some("143")),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(answers.verify(2, Some("1")), Verdict::Unknown);
+    }
+}
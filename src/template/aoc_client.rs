@@ -0,0 +1,438 @@
+/// Module with a client for adventofcode.com that downloads inputs and puzzles and submits answers.
+///
+/// Requests are authenticated with the session cookie of a logged in browser. It is read from the
+/// `AOC_SESSION` environment variable or from `~/.adventofcode.session`, the file `aoc-cli` uses.
+/// The site can be replaced by another server, e.g. a local mock, through `AOC_BASE_URL`.
+use std::{env, fmt::Display, fs, io, path::Path, time::Duration};
+
+use regex::Regex;
+
+use crate::template::inputs::{self, Problem};
+use crate::template::{get_data_path, html};
+use crate::{PuzzleId, Year};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("advent_of_code template ", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Request(String),
+    BadStatus(u16),
+    /// The server responded with something that is not a puzzle input.
+    InvalidInput(Problem),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found, set `AOC_SESSION` or write it to `~/.adventofcode.session`."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => write!(
+                f,
+                "server responded with status {status}, is the session cookie valid and the puzzle unlocked?"
+            ),
+            AocClientError::InvalidInput(problem) => write!(
+                f,
+                "the downloaded input {problem}, is the session cookie valid and the puzzle unlocked?"
+            ),
+            AocClientError::IO(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AocClientError {
+    fn from(e: std::io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+        }
+    }
+}
+
+/// The hint the server gives for an incorrect answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    /// An incorrect answer, with the time to wait before the next submission if the server sent it.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, with the time left to wait if the server sent it.
+    Cooldown {
+        wait: Option<Duration>,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+/// Reads the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+#[must_use]
+pub fn read_session() -> Option<String> {
+    let session = env::var("AOC_SESSION").ok().or_else(|| {
+        let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+        fs::read_to_string(Path::new(&home).join(".adventofcode.session")).ok()
+    })?;
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        }
+    }
+
+    /// Creates a client for `AOC_BASE_URL`, or adventofcode.com if it is not set.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Self::new(&base_url, read_session())
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> Result<String, AocClientError> {
+        self.session
+            .as_ref()
+            .map(|session| format!("session={session}"))
+            .ok_or(AocClientError::SessionNotFound)
+    }
+
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}/input", self.day_url(puzzle)))
+            .set("Cookie", &self.cookie()?)
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the HTML of a puzzle page. The second part is only included with a session cookie.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let mut request = self.agent.get(&self.day_url(puzzle));
+
+        if let Ok(cookie) = self.cookie() {
+            request = request.set("Cookie", &cookie);
+        }
+
+        Ok(request.call()?.into_string()?)
+    }
+
+    /// Fetches the HTML of the calendar of a year, which shows the stars of the logged in user.
+    pub fn get_calendar(&self, year: Year) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}/{year}", self.base_url))
+            .set("Cookie", &self.cookie()?)
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the JSON of a private leaderboard.
+    pub fn get_leaderboard(&self, year: Year, id: u64) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&format!(
+                "{}/{year}/leaderboard/private/view/{id}.json",
+                self.base_url
+            ))
+            .set("Cookie", &self.cookie()?)
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Submits an answer and returns the server's verdict along with its message as Markdown.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<(Submission, String), AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let message = html::to_markdown(&response.into_string()?);
+        Ok((parse_submission(&message), message))
+    }
+}
+
+fn parse_wait(message: &str) -> Option<Duration> {
+    let re = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let captures = re.captures(message)?;
+
+    let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = captures[2].parse().ok()?;
+
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+/// Reads the penalty of an incorrect answer, e.g. "Please wait one minute before trying again".
+fn parse_penalty(message: &str) -> Option<Duration> {
+    let re = Regex::new(r"(?i)wait (one|five|ten|\d+) minutes?").unwrap();
+    let captures = re.captures(message)?;
+
+    let minutes: u64 = match captures[1].to_lowercase().as_str() {
+        "one" => 1,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// Reads the verdict from the message the server responds with to a submission.
+#[must_use]
+pub fn parse_submission(message: &str) -> Submission {
+    if message.contains("That's the right answer") {
+        Submission::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Submission::Incorrect {
+            hint,
+            wait: parse_penalty(message),
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Submission::Cooldown {
+            wait: parse_wait(message),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        Submission::WrongLevel
+    } else {
+        Submission::Unknown
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Downloads the puzzle description and saves it, overwriting an existing file.
+pub fn download_puzzle(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    let description = html::to_markdown(&AocClient::from_env().get_puzzle(puzzle)?);
+    write_file(&get_puzzle_path(puzzle), &description)?;
+    Ok(description)
+}
+
+/// Downloads the input and saves it, overwriting an existing file. Responses that are not an input,
+/// e.g. an error page, are not saved.
+pub fn download_input(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    let input = AocClient::from_env().get_input(puzzle)?;
+
+    if let Some(problem) = inputs::check(&input).into_iter().find(Problem::is_fatal) {
+        return Err(AocClientError::InvalidInput(problem));
+    }
+
+    write_file(&get_input_path(puzzle), &input)?;
+    Ok(input)
+}
+
+/// Submits an answer and prints the server's response.
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Submission, AocClientError> {
+    let (submission, message) = AocClient::from_env().submit(puzzle, part, result)?;
+    println!("{message}");
+    Ok(submission)
+}
+
+fn get_input_path(puzzle: PuzzleId) -> String {
+    get_data_path("inputs", puzzle, "txt")
+}
+
+/// Returns the path of the downloaded puzzle description, e.g. `data/2023/puzzles/05.md`.
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    get_data_path("puzzles", puzzle, "md")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+
+    use super::{parse_submission, AocClient, AocClientError, Hint, Submission};
+    use crate::{day, year, PuzzleId};
+
+    /// Serves a single request with a canned response and returns the raw request.
+    fn mock_server(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2023), day!(5))
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, server) = mock_server("200 OK", "1\n2\n");
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        assert_eq!(client.get_input(puzzle()).unwrap(), "1\n2\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn requires_session_for_input() {
+        let client = AocClient::new("http://127.0.0.1:1", None);
+        assert!(matches!(
+            client.get_input(puzzle()),
+            Err(AocClientError::SessionNotFound)
+        ));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = mock_server("400 Bad Request", "Please log in.");
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        assert!(matches!(
+            client.get_input(puzzle()),
+            Err(AocClientError::BadStatus(400))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let body = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+        let (base_url, server) = mock_server("200 OK", body);
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        let (submission, message) = client.submit(puzzle(), 2, "46").unwrap();
+        assert_eq!(submission, Submission::Correct);
+        assert!(message.contains("*one gold star*"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=46"));
+    }
+
+    #[test]
+    fn parses_submissions() {
+        assert_eq!(
+            parse_submission("That's not the right answer; your answer is too high. Please wait one minute before trying again."),
+            Submission::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            parse_submission("That's not the right answer. If you're stuck, ..."),
+            Submission::Incorrect {
+                hint: None,
+                wait: None
+            }
+        );
+        assert_eq!(
+            parse_submission("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Submission::Cooldown {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            parse_submission(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Submission::WrongLevel
+        );
+        assert_eq!(parse_submission("Hello"), Submission::Unknown);
+    }
+}
@@ -0,0 +1,290 @@
+/// Module that keeps a local history of benchmark results, keyed by git commit and timestamp.
+/// Every `cargo time` appends one run to the history file so runs can be compared later.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::get_data_dir;
+use crate::template::runner::{PartResult, Statistic};
+use crate::PuzzleId;
+
+/// Returns the path of the history file, e.g. `data/benchmarks.csv`.
+#[must_use]
+pub fn get_history_path() -> String {
+    format!("{}/benchmarks.csv", get_data_dir())
+}
+
+static HEADER: &str = "commit,timestamp_ms,year,day,part,mean_ns,median_ns";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not read or write benchmark history: {e}"),
+        }
+    }
+}
+
+/// The timings of one part in one benchmark run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub commit: String,
+    /// Milliseconds since the unix epoch.
+    pub timestamp: u128,
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub mean: Duration,
+    pub median: Duration,
+}
+
+impl Entry {
+    fn is_same_run(&self, other: &Entry) -> bool {
+        self.commit == other.commit && self.timestamp == other.timestamp
+    }
+
+    fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Median => self.median,
+        }
+    }
+}
+
+/// The change of a part's timing between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// The relative change in percent, positive if the part got slower.
+    pub percent: f64,
+    pub is_regression: bool,
+}
+
+/// Returns the hash of a git revision, e.g. `HEAD`.
+#[must_use]
+pub fn resolve_rev(rev: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet", rev])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn parse_line(line: &str) -> Result<Entry, Error> {
+    let parts: Vec<&str> = line.split(',').collect();
+
+    let malformed = || Error::Parser(format!("Malformed history line: {line}"));
+
+    let [commit, timestamp, year, day, part, mean, median] = parts[..] else {
+        return Err(malformed());
+    };
+
+    Ok(Entry {
+        commit: commit.to_string(),
+        timestamp: timestamp.parse().map_err(|_| malformed())?,
+        puzzle: PuzzleId::new(
+            year.parse().map_err(|_| malformed())?,
+            day.parse().map_err(|_| malformed())?,
+        ),
+        part: part.parse().map_err(|_| malformed())?,
+        mean: Duration::from_nanos(mean.parse().map_err(|_| malformed())?),
+        median: Duration::from_nanos(median.parse().map_err(|_| malformed())?),
+    })
+}
+
+fn parse(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .filter(|line| !line.is_empty() && *line != HEADER)
+        .map(parse_line)
+        .collect()
+}
+
+fn format_entry(entry: &Entry) -> String {
+    format!(
+        "{},{},{},{},{},{},{}",
+        entry.commit,
+        entry.timestamp,
+        entry.puzzle.year,
+        entry.puzzle.day,
+        entry.part,
+        entry.mean.as_nanos(),
+        entry.median.as_nanos()
+    )
+}
+
+pub fn read() -> Result<Vec<Entry>, Error> {
+    match fs::read_to_string(get_history_path()) {
+        Ok(s) => parse(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Appends the benched parts with an answer and the parse phase to the history as a new run.
+pub fn append(results: &[PartResult]) -> Result<(), Error> {
+    let commit = resolve_rev("HEAD").unwrap_or_else(|| "unknown".into());
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+
+    let is_new = fs::metadata(get_history_path()).is_err();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path())?;
+
+    if is_new {
+        writeln!(file, "{HEADER}")?;
+    }
+
+    for result in results {
+        if !result.is_benched() {
+            continue;
+        }
+
+        let entry = Entry {
+            commit: commit.clone(),
+            timestamp,
+            puzzle: result.puzzle,
+            part: result.part,
+            mean: result.stats.mean,
+            median: result.stats.median,
+        };
+
+        writeln!(file, "{}", format_entry(&entry))?;
+    }
+
+    Ok(())
+}
+
+/// Splits the history into runs, in the order they were recorded.
+#[must_use]
+pub fn runs(entries: &[Entry]) -> Vec<Vec<Entry>> {
+    let mut runs: Vec<Vec<Entry>> = vec![];
+
+    for entry in entries {
+        match runs.last_mut() {
+            Some(run) if run[0].is_same_run(entry) => run.push(entry.clone()),
+            _ => runs.push(vec![entry.clone()]),
+        }
+    }
+
+    runs
+}
+
+/// Compares the parts present in both runs. `threshold` is the slowdown in percent above which a part counts as a regression.
+#[must_use]
+pub fn compare(
+    baseline: &[Entry],
+    current: &[Entry],
+    statistic: Statistic,
+    threshold: f64,
+) -> Vec<Delta> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let base = baseline
+                .iter()
+                .find(|b| b.puzzle == entry.puzzle && b.part == entry.part)?;
+
+            let (baseline, current) = (base.get(statistic), entry.get(statistic));
+            let percent = (current.as_secs_f64() / baseline.as_secs_f64().max(1e-9) - 1.0) * 100.0;
+
+            Some(Delta {
+                puzzle: entry.puzzle,
+                part: entry.part,
+                baseline,
+                current,
+                percent,
+                is_regression: percent > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, parse, runs, Error};
+    use crate::template::runner::Statistic;
+
+    fn get_mock_history() -> String {
+        [
+            "commit,timestamp_ms,year,day,part,mean_ns,median_ns",
+            "aaa,100,2023,1,1,1000,900",
+            "aaa,100,2023,1,2,2000,1900",
+            "bbb,200,2023,1,1,1050,1000",
+            "bbb,200,2023,1,2,3000,2800",
+            "bbb,200,2023,2,1,500,500",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn parses_history() {
+        let entries = parse(&get_mock_history()).unwrap();
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[0].commit, "aaa");
+        assert_eq!(entries[0].timestamp, 100);
+        assert_eq!(entries[4].puzzle.day.into_inner(), 2);
+        assert_eq!(entries[4].median, Duration::from_nanos(500));
+    }
+
+    #[test]
+    fn errors_on_malformed_history() {
+        assert!(matches!(parse("aaa,100,2023,1"), Err(Error::Parser(_))));
+    }
+
+    #[test]
+    fn groups_runs() {
+        let entries = parse(&get_mock_history()).unwrap();
+        let runs = runs(&entries);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].len(), 2);
+        assert_eq!(runs[1].len(), 3);
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let entries = parse(&get_mock_history()).unwrap();
+        let runs = runs(&entries);
+        let deltas = compare(&runs[0], &runs[1], Statistic::Mean, 10.0);
+
+        assert_eq!(deltas.len(), 2);
+        assert!((deltas[0].percent - 5.0).abs() < 1e-6);
+        assert!(!deltas[0].is_regression);
+        assert!((deltas[1].percent - 50.0).abs() < 1e-6);
+        assert!(deltas[1].is_regression);
+    }
+
+    #[test]
+    fn compares_medians() {
+        let entries = parse(&get_mock_history()).unwrap();
+        let runs = runs(&entries);
+        let deltas = compare(&runs[0], &runs[1], Statistic::Median, 10.0);
+        assert!(deltas[0].is_regression);
+    }
+}
@@ -1,53 +1,288 @@
-use std::io;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+use std::{io, panic, thread};
 
 use crate::template::{
+    answers::Verdict,
+    bench_history,
+    inputs::{self, Problem},
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    report::{self, Format},
+    runner::{
+        format_part, print_part, report_parts_to, Abandon, DurationStats, Failure, PartEvent,
+        PartResult, RunOptions, Solution, Statistic, PARSE_PART,
+    },
+    vault, Error as TemplateError, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, PuzzleId, Year};
+
+/// Options of `cargo all` that do not apply to single days.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllOptions {
+    pub is_release: bool,
+    pub is_verify: bool,
+    pub statistic: Statistic,
+    pub timeouts: Timeouts,
+    /// How many days run at the same time. Defaults to one per CPU, or to one with `--time` so that
+    /// benchmarks do not compete for the CPU.
+    pub jobs: Option<usize>,
+}
+
+impl AllOptions {
+    fn jobs(&self, options: &RunOptions) -> usize {
+        match self.jobs {
+            Some(jobs) => jobs.max(1),
+            None if options.is_timed => 1,
+            None => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+}
+
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    options: &RunOptions,
+    all_options: &AllOptions,
+) -> Result<(), TemplateError> {
+    let AllOptions {
+        is_release,
+        is_verify,
+        statistic,
+        timeouts,
+        ..
+    } = *all_options;
 
-pub fn handle(is_release: bool, is_timed: bool) {
     let mut timings: Vec<Timings> = vec![];
+    let mut all_results: Vec<PartResult> = vec![];
+    let mut failures: Vec<String> = vec![];
+
+    let format = options.format;
+    let is_text = format == Format::Text;
+
+    let jobs = all_options.jobs(options);
+    let is_buffered = jobs > 1;
+
+    if is_buffered && options.is_timed {
+        eprintln!("Running {jobs} days at the same time, benchmarks may be less accurate.");
+    }
+
+    let run_options = RunOptions {
+        // submitting is only supported for single days.
+        submit: None,
+        // concurrent days would print over each other, their results are printed in order instead.
+        format: if is_buffered { Format::Json } else { format },
+        ..*options
+    };
+
+    let days: Vec<(PuzzleId, Option<&Solution>)> = all_days()
+        .map(|day| {
+            let puzzle = PuzzleId::new(year, day);
+            (puzzle, solutions.iter().find(|s| s.puzzle == puzzle))
+        })
+        .collect();
+
+    let print_header = |puzzle: PuzzleId| {
+        if is_text {
+            if puzzle.day > 1 {
+                println!();
+            }
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+            println!("------");
         }
+    };
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    let run = |&(puzzle, solution): &(PuzzleId, Option<&Solution>)| {
+        let solution = *solution?;
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        Some(read_input(puzzle).and_then(|(input, problems)| {
+            // live output shows the problems before the parts run, buffered output along with the results.
+            if !is_buffered {
+                inputs::warn(puzzle, &problems);
+            }
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            Ok(DayResults {
+                results: run_isolated(solution, input, run_options, timeouts)?,
+                problems,
+            })
+        }))
+    };
+
+    let mut report = |puzzle: PuzzleId, outcome: Option<Result<DayResults, Error>>| {
+        let Some(outcome) = outcome else {
+            if is_text {
+                println!("Not solved.");
+            }
+            return;
+        };
+
+        match outcome {
+            Ok(DayResults { results, problems }) => {
+                if is_buffered {
+                    inputs::warn(puzzle, &problems);
+                }
+
+                for result in &results {
+                    let Some(failure) = &result.failure else {
+                        if is_text && is_buffered {
+                            print_part(result);
+                        }
+                        continue;
+                    };
+
+                    if is_text {
+                        println!(
+                            "\r{}: {ANSI_ITALIC}{failure}{ANSI_RESET}",
+                            format_label(result.part)
+                        );
+                    }
+
+                    failures.push(format!("{puzzle} {} ({failure})", format_part(result.part)));
+                }
+
+                timings.push(collect_timings(puzzle, &results, statistic));
+                all_results.extend(results);
+            }
+            Err(Error::IO(e)) => {
+                eprintln!("Could not read input for {puzzle}: {e}");
+            }
+            Err(Error::Input(problem)) => {
+                inputs::warn(puzzle, &[problem]);
+            }
         }
-    });
+    };
+
+    if is_buffered {
+        run_ordered(&days, jobs, run, |day, outcome| {
+            print_header(day.0);
+            report(day.0, outcome);
+        });
+    } else {
+        for day in &days {
+            print_header(day.0);
+            report(day.0, run(day));
+        }
+    }
 
-    if is_timed {
+    report::print(format, &all_results);
+
+    let regressions: Vec<String> = all_results
+        .iter()
+        .filter(|r| matches!(r.verdict, Verdict::Incorrect { .. }))
+        .map(|r| format!("{} {}", r.puzzle, format_part(r.part)))
+        .collect();
+
+    if options.is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            match readme_benchmarks::update(timings, total_millis, statistic) {
+                Ok(()) if is_text => println!("Successfully updated README with benchmarks."),
+                Ok(()) => {}
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            match bench_history::append(&all_results) {
+                Ok(()) if is_text => println!("Recorded benchmarks in history."),
+                Ok(()) => {}
+                Err(_) => {
+                    eprintln!("Failed to record benchmarks in history.");
+                }
+            }
         }
     }
+
+    if !failures.is_empty() {
+        eprintln!("\nFailed to run: {}", failures.join(", "));
+    }
+
+    if is_verify && !regressions.is_empty() {
+        return Err(TemplateError::Failed(format!(
+            "incorrect answers for: {}",
+            regressions.join(", ")
+        )));
+    }
+
+    if is_verify && !failures.is_empty() {
+        return Err(TemplateError::Failed(format!(
+            "{} part(s) failed to run.",
+            failures.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Calls `run` for every item on `jobs` threads, and `report` for every result in the order of the items.
+fn run_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(&T, R),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let (next, run, sender) = (&next, &run, sender.clone());
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, run(item))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        // results arrive in the order they finish, they wait here until all items before them are reported.
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+
+        for (index, result) in receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&reported) {
+                report(&items[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+/// Returns a label for a part as the runner prints it, e.g. `Part 1` or `Parse`.
+fn format_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
 }
 
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
-    Parser(String),
     IO(io::Error),
+    /// The input has a problem that keeps the solution from running, see [`Problem::is_fatal`].
+    Input(Problem),
 }
 
 impl From<std::io::Error> for Error {
@@ -56,199 +291,448 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "could not read input: {e}"),
+            Error::Input(problem) => write!(f, "the input {problem}"),
+        }
+    }
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+/// Limits on how long a solution may run in `cargo all`. With `--time`, benching counts towards them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    /// The limit for a whole day, including its parse phase.
+    pub day: Option<Duration>,
+    /// The limit for every part and the parse phase.
+    pub part: Option<Duration>,
+}
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+/// The results of a day, along with the problems of its input that did not keep it from running.
+#[derive(Debug)]
+pub struct DayResults {
+    pub results: Vec<PartResult>,
+    pub problems: Vec<Problem>,
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+/// Reads the puzzle input of a day and checks it for problems, see [`inputs::verify`].
+pub fn read_input(puzzle: PuzzleId) -> Result<(String, Vec<Problem>), Error> {
+    let input = vault::read_input(puzzle)?;
+    let problems = inputs::verify(puzzle, &input);
 
-        if is_release {
-            args.push("--release");
-        }
+    if let Some(problem) = problems.iter().find(|problem| problem.is_fatal()) {
+        return Err(Error::Input(*problem));
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+    Ok((input, problems))
+}
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+thread_local! {
+    static IS_ISOLATED: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+/// Keeps the panics of isolated solutions from being printed, they are reported as [`Failure::Panic`].
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
 
-        let mut output = vec![];
+        panic::set_hook(Box::new(move |info| {
+            if !IS_ISOLATED.get() {
+                default_hook(info);
+                return;
+            }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let location = info
+                .location()
+                .map(|location| format!(" at {location}"))
+                .unwrap_or_default();
+
+            PANIC_MESSAGE.set(Some(format!("{message}{location}")));
+        }));
+    });
+}
+
+/// Runs a solution on its own thread, so that a panic or a timeout only fails the part it happened in.
+/// The parts that finished before are still returned.
+///
+/// A thread that timed out can not be stopped, it keeps running in the background until its current
+/// part returns. Its parts are abandoned, so that they do not print into the output of later days.
+pub fn run_isolated(
+    solution: Solution,
+    input: String,
+    options: RunOptions,
+    timeouts: Timeouts,
+) -> io::Result<Vec<PartResult>> {
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let abandon = Abandon::default();
+    let worker_abandon = abandon.clone();
+
+    let handle = thread::Builder::new()
+        .name(solution.puzzle.to_string())
+        .spawn(move || {
+            IS_ISOLATED.set(true);
+            report_parts_to(sender, worker_abandon);
+
+            panic::catch_unwind(|| solution.run(&input, &options))
+                .map_err(|_| PANIC_MESSAGE.take().unwrap_or_default())
+        })?;
+
+    let start = Instant::now();
+    let mut part_start = start;
+    // the part that has not reported yet, none once both parts finished.
+    let mut pending = Some(1);
+    let mut results = vec![];
+
+    loop {
+        let deadline = [
+            timeouts.day.map(|timeout| (start + timeout, timeout)),
+            timeouts.part.map(|timeout| (part_start + timeout, timeout)),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        let event = match deadline {
+            Some((deadline, _)) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        match event {
+            Ok(PartEvent::Started(started)) => {
+                pending = Some(started);
+                part_start = Instant::now();
+            }
+            Ok(PartEvent::Finished(result)) => {
+                // until the next part starts, a timeout or panic belongs to it.
+                pending = (result.part < 2).then(|| result.part + 1);
+                part_start = Instant::now();
+                results.push(*result);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                abandon.abandon();
+                let timeout = deadline.map(|(_, timeout)| timeout).unwrap_or_default();
+                if let Some(part) = pending {
+                    results.push(failed_part(solution, part, Failure::Timeout(timeout)));
+                }
+                return Ok(results);
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    let panic = match handle.join() {
+        Ok(Ok(_)) => None,
+        Ok(Err(message)) => Some(message),
+        Err(_) => Some(String::new()),
+    };
 
-        Ok(output)
+    if let (Some(message), Some(part)) = (panic, pending) {
+        results.push(failed_part(solution, part, Failure::Panic(message)));
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    Ok(results)
+}
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+fn failed_part(solution: Solution, part: u8, failure: Failure) -> PartResult {
+    PartResult {
+        puzzle: solution.puzzle,
+        part,
+        answer: None,
+        verdict: Verdict::Unknown,
+        samples: 0,
+        stats: DurationStats::default(),
+        failure: Some(failure),
+    }
+}
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+/// Only benched parts that produced an answer and the parse phase contribute to the timings.
+fn collect_timings(puzzle: PuzzleId, results: &[PartResult], statistic: Statistic) -> Timings {
+    let mut timings = Timings {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    for result in results {
+        if !result.is_benched() {
+            continue;
+        }
 
-                timings.total_nanos += nanos;
-            });
+        let duration = result.stats.get(statistic);
+        let timing_str = Some(format!("{duration:.1?}"));
 
-        timings
+        match result.part {
+            PARSE_PART => timings.parse = timing_str,
+            1 => timings.part_1 = timing_str,
+            2 => timings.part_2 = timing_str,
+            _ => {}
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = duration.as_nanos() as f64;
+        timings.total_nanos += nanos;
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    timings
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{collect_timings, run_isolated, run_ordered, Timeouts};
+    use crate::template::answers::Verdict;
+    use crate::template::report::Format;
+    use crate::template::runner::{
+        run_part, DurationStats, Failure, PartResult, RunOptions, Solution, Statistic, PARSE_PART,
+    };
+    use crate::{day, year, PuzzleId};
+
+    fn part_result(part: u8, answer: Option<&str>, nanos: u64, samples: u128) -> PartResult {
+        let duration = Duration::from_nanos(nanos);
+        PartResult {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            part,
+            answer: answer.map(Into::into),
+            verdict: Verdict::Unknown,
+            samples,
+            stats: DurationStats::from_samples(&mut [duration]),
+            failure: None,
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    #[test]
+    fn test_benched_parts() {
+        let res = collect_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[
+                part_result(1, Some("0"), 74, 100_000),
+                part_result(2, Some("10"), 74_130_000, 99_999),
+            ],
+            Statistic::Mean,
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+    }
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+    #[test]
+    fn test_unbenched_parts() {
+        let res = collect_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[
+                part_result(1, Some("0"), 2_000, 1),
+                part_result(2, Some("10"), 100_000_000, 10),
+            ],
+            Statistic::Mean,
+        );
+        assert_eq!(res.total_nanos, 100_000_000_f64);
+        assert!(res.part_1.is_none());
+        assert_eq!(res.part_2.unwrap(), "100.0ms");
+    }
 
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[part_result(1, None, 10, 10), part_result(2, None, 10, 10)],
+            Statistic::Mean,
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert!(res.part_1.is_none());
+        assert!(res.part_2.is_none());
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_parse_phase() {
+        let res = collect_timings(
+            PuzzleId::new(year!(2023), day!(1)),
+            &[
+                part_result(PARSE_PART, None, 1_000, 10),
+                part_result(1, Some("0"), 2_000, 10),
+                part_result(2, None, 3_000, 10),
+            ],
+            Statistic::Mean,
+        );
+        assert_eq!(res.total_nanos, 3_000_f64);
+        assert_eq!(res.parse.unwrap(), "1.0µs");
+        assert_eq!(res.part_1.unwrap(), "2.0µs");
+        assert!(res.part_2.is_none());
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+    const OPTIONS: RunOptions = RunOptions {
+        is_timed: false,
+        submit: None,
+        format: Format::Json,
+        bench: crate::template::runner::BenchOptions {
+            warmup: Duration::ZERO,
+            budget: Duration::ZERO,
+        },
+        skip_verify: true,
+    };
 
-        use crate::day;
+    fn panicking_parts(input: &str, options: &RunOptions) -> Vec<PartResult> {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        vec![
+            run_part(|input: &str| Some(input.len()), input, puzzle, 1, options),
+            run_part(
+                |_: &str| -> Option<u32> { panic!("oops") },
+                input,
+                puzzle,
+                2,
+                options,
+            ),
+        ]
+    }
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    fn slow_parts(input: &str, options: &RunOptions) -> Vec<PartResult> {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let slow = |_: &str| -> Option<u32> {
+            thread::sleep(Duration::from_millis(500));
+            None
+        };
+        vec![
+            run_part(|input: &str| Some(input.len()), input, puzzle, 1, options),
+            run_part(slow, input, puzzle, 2, options),
+        ]
+    }
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    fn slow_between_parts(input: &str, options: &RunOptions) -> Vec<PartResult> {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let part_one = run_part(|input: &str| Some(input.len()), input, puzzle, 1, options);
+        thread::sleep(Duration::from_millis(500));
+        vec![
+            part_one,
+            run_part(|input: &str| Some(input.len()), input, puzzle, 2, options),
+        ]
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+    fn panicking_after_parts(input: &str, options: &RunOptions) -> Vec<PartResult> {
+        let puzzle = PuzzleId::new(year!(2023), day!(1));
+        let _results = vec![
+            run_part(|input: &str| Some(input.len()), input, puzzle, 1, options),
+            run_part(|input: &str| Some(input.len()), input, puzzle, 2, options),
+        ];
+        panic!("oops");
+    }
+
+    fn solution(run_parts: fn(&str, &RunOptions) -> Vec<PartResult>) -> Solution {
+        Solution {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            run_parts,
         }
     }
+
+    #[test]
+    fn test_panics() {
+        let results = run_isolated(
+            solution(panicking_parts),
+            "abc".into(),
+            OPTIONS,
+            Timeouts::default(),
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer.as_deref(), Some("3"));
+        assert_eq!(results[1].part, 2);
+
+        let Some(Failure::Panic(message)) = &results[1].failure else {
+            panic!("expected a panic, got {:?}", results[1].failure);
+        };
+        assert!(message.starts_with("oops at src/template/commands/all.rs:"));
+    }
+
+    #[test]
+    fn test_panics_after_parts() {
+        let results = run_isolated(
+            solution(panicking_after_parts),
+            "abc".into(),
+            OPTIONS,
+            Timeouts::default(),
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].part, 1);
+        assert_eq!(results[1].part, 2);
+        assert!(results.iter().all(|result| result.failure.is_none()));
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let timeouts = Timeouts {
+            day: None,
+            part: Some(Duration::from_millis(50)),
+        };
+        let results = run_isolated(solution(slow_parts), "abc".into(), OPTIONS, timeouts).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer.as_deref(), Some("3"));
+        assert_eq!(
+            results[1].failure,
+            Some(Failure::Timeout(Duration::from_millis(50)))
+        );
+    }
+
+    #[test]
+    fn test_timeouts_between_parts() {
+        let timeouts = Timeouts {
+            day: None,
+            part: Some(Duration::from_millis(50)),
+        };
+        let results = run_isolated(
+            solution(slow_between_parts),
+            "abc".into(),
+            OPTIONS,
+            timeouts,
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].part, 1);
+        assert!(results[0].failure.is_none());
+        assert_eq!(results[1].part, 2);
+        assert_eq!(
+            results[1].failure,
+            Some(Failure::Timeout(Duration::from_millis(50)))
+        );
+    }
+
+    #[test]
+    fn test_ordered_results() {
+        // the first items finish last.
+        let items: Vec<u64> = (0..8).collect();
+        let mut reported = vec![];
+
+        run_ordered(
+            &items,
+            4,
+            |&item| {
+                thread::sleep(Duration::from_millis(40 - 5 * item));
+                item * 10
+            },
+            |&item, result| reported.push((item, result)),
+        );
+
+        let expected: Vec<(u64, u64)> = items.iter().map(|&item| (item, item * 10)).collect();
+        assert_eq!(reported, expected);
+    }
 }
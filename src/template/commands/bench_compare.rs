@@ -0,0 +1,74 @@
+use crate::template::{
+    bench_history::{self, Entry},
+    runner::{format_part, Statistic},
+    Error, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(7)]
+}
+
+/// Compare the latest recorded benchmark run against the previous run, or against the latest run of `rev`.
+pub fn handle(rev: Option<&str>, threshold: f64, statistic: Statistic) -> Result<(), Error> {
+    let entries = bench_history::read()?;
+
+    let runs = bench_history::runs(&entries);
+
+    let Some((current, previous)) = runs.split_last() else {
+        return Err(Error::Failed(
+            "no benchmark history yet, run `cargo time` first.".into(),
+        ));
+    };
+
+    let baseline: Option<&Vec<Entry>> = match rev {
+        None => previous.last(),
+        Some(rev) => {
+            let Some(commit) = bench_history::resolve_rev(rev) else {
+                return Err(Error::Failed(format!("unknown revision: {rev}")));
+            };
+
+            previous.iter().rev().find(|run| run[0].commit == commit)
+        }
+    };
+
+    let Some(baseline) = baseline else {
+        return Err(Error::Failed(
+            "no earlier benchmark run to compare against.".into(),
+        ));
+    };
+
+    println!(
+        "Comparing {ANSI_BOLD}{}{ANSI_RESET} against {ANSI_BOLD}{}{ANSI_RESET} ({statistic}, regression threshold {threshold}%)\n",
+        short(&current[0].commit),
+        short(&baseline[0].commit),
+    );
+
+    let deltas = bench_history::compare(baseline, current, statistic, threshold);
+
+    for delta in &deltas {
+        let flag = if delta.is_regression {
+            format!(" {ANSI_BOLD}⚠ regression{ANSI_RESET}")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "{} {}: {:.1?} → {:.1?} {ANSI_ITALIC}({:+.1}%){ANSI_RESET}{flag}",
+            delta.puzzle,
+            format_part(delta.part),
+            delta.baseline,
+            delta.current,
+            delta.percent
+        );
+    }
+
+    let regressions = deltas.iter().filter(|d| d.is_regression).count();
+
+    if regressions > 0 {
+        return Err(Error::Failed(format!(
+            "{regressions} part(s) got slower by more than {threshold}%."
+        )));
+    }
+
+    Ok(())
+}
@@ -1,15 +1,75 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
-
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+use std::io;
+
+use crate::template::aoc_client;
+use crate::template::get_data_path;
+use crate::template::inputs::{self, Checksums, Problem};
+use crate::template::vault;
+use crate::template::Error;
+use crate::PuzzleId;
+
+/// The state of the saved input of a day.
+enum SavedInput {
+    /// The input that was downloaded, it does not need to be downloaded again.
+    Downloaded,
+    /// An input that does not match the recorded hash, e.g. after editing it by hand.
+    Modified,
+    /// No input, a placeholder or an input that was not downloaded by this command.
+    Unknown,
+}
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+fn inspect(puzzle: PuzzleId, checksums: &Checksums) -> Result<SavedInput, Error> {
+    let input = match vault::read_input(puzzle) {
+        Ok(input) => input,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
     };
+
+    let saved = match checksums.get(puzzle.day) {
+        Some(recorded) if inputs::hash(&input) == recorded => SavedInput::Downloaded,
+        Some(_) if !inputs::check(&input).iter().any(Problem::is_fatal) => SavedInput::Modified,
+        _ => SavedInput::Unknown,
+    };
+
+    Ok(saved)
+}
+
+pub fn handle(puzzle: PuzzleId, force: bool) -> Result<(), Error> {
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let mut checksums = Checksums::read(puzzle.year)?;
+
+    let is_kept = !force
+        && match inspect(puzzle, &checksums)? {
+            SavedInput::Downloaded => {
+                println!("🎄 Input is already downloaded to \"{input_path}\", pass `--force` to download it again.");
+                true
+            }
+            SavedInput::Modified => {
+                eprintln!(
+                    "Warning: {input_path} {}, pass `--force` to replace it.",
+                    Problem::Modified
+                );
+                true
+            }
+            SavedInput::Unknown => false,
+        };
+
+    if !is_kept {
+        let input = aoc_client::download_input(puzzle)?;
+
+        println!("🎄 Successfully wrote input to \"{input_path}\".");
+        inputs::warn(puzzle, &inputs::check(&input));
+
+        checksums.insert(puzzle.day, inputs::hash(&input));
+        if let Err(e) = checksums.write(puzzle.year) {
+            eprintln!("Failed to record the hash of the input: {e}");
+        }
+    }
+
+    aoc_client::download_puzzle(puzzle)?;
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        aoc_client::get_puzzle_path(puzzle)
+    );
+
+    Ok(())
 }
@@ -1,16 +1,73 @@
-use std::process;
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+};
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client::{self, AocClientError};
+use crate::template::{markdown, Error};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Reads the cached puzzle description, downloading it if there is none yet or if `refresh` is set.
+fn read_description(puzzle: PuzzleId, refresh: bool) -> Result<String, AocClientError> {
+    if !refresh {
+        match fs::read_to_string(aoc_client::get_puzzle_path(puzzle)) {
+            Ok(description) => return Ok(description),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    aoc_client::download_puzzle(puzzle)
+}
+
+/// Shows text in the pager of `$PAGER`, falling back to `less`. Prints the text directly if the
+/// output is not a terminal or no pager is available.
+fn page(text: &str, use_pager: bool) {
+    if use_pager && io::stdout().is_terminal() {
+        let pager = env::var("PAGER").unwrap_or_else(|_| "less -FRX".into());
+        let mut args = pager.split_whitespace();
+
+        let child = args.next().and_then(|program| {
+            Command::new(program)
+                .args(args)
+                .stdin(Stdio::piped())
+                .spawn()
+                .ok()
+        });
+
+        if let Some(mut child) = child {
+            if let Some(mut stdin) = child.stdin.take() {
+                // the pager closes its input when it quits early.
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+            return;
+        }
+    }
+
+    print!("{text}");
+}
+
+pub fn handle(
+    puzzle: PuzzleId,
+    part: Option<u8>,
+    refresh: bool,
+    use_pager: bool,
+) -> Result<(), Error> {
+    let description = read_description(puzzle, refresh)?;
+
+    let selected = match part {
+        None => Some(description.as_str()),
+        Some(part) => markdown::select_part(&description, part),
     };
+
+    let Some(selected) = selected else {
+        return Err(Error::Failed(format!(
+            "the description of {puzzle} has no part two yet, pass `--refresh` to download it again."
+        )));
+    };
+
+    page(&markdown::to_ansi(selected), use_pager);
+    Ok(())
 }
@@ -1,94 +1,746 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
+use std::{fs, path::Path};
 
-use crate::Day;
+use crate::template::aoc_client::{self, AocClient, AocClientError};
+use crate::template::{get_data_path, get_path_for_bin, html, Error};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The template that is used when the repository has no [`TEMPLATE_PATH`].
+const DEFAULT_TEMPLATE: &str = include_str!("../../../templates/solution.rs.tmpl");
 
-pub fn part_one(input: &str) -> Option<u32> {
-    None
+/// The user-editable template of new solutions.
+pub const TEMPLATE_PATH: &str = "templates/solution.rs.tmpl";
+
+/// Options that change the scaffolded solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaffoldOptions {
+    /// The return type of both parts.
+    pub answer_type: String,
+    /// Parse the input into a `DenseGrid<char>` in a parse phase.
+    pub grid: bool,
+    /// Add a regex for parsing lines.
+    pub regex: bool,
+    /// Download the puzzle and pre-fill the example and its expected answers.
+    pub fetch: bool,
+    /// Overwrite existing files with new contents.
+    pub force: bool,
+    /// Only report what would change.
+    pub dry_run: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+impl Default for ScaffoldOptions {
+    fn default() -> Self {
+        Self {
+            answer_type: "u32".into(),
+            grid: false,
+            regex: false,
+            fetch: false,
+            force: false,
+            dry_run: false,
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// The values that are filled into the placeholders of a template.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateContext {
+    pub title: Option<String>,
+    pub example_answers: [Option<String>; 2],
+}
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
+/// Reads the title of a puzzle from its description in Markdown, e.g. `Day 1: Trebuchet?!`.
+fn parse_title(description: &str) -> Option<String> {
+    description.lines().find_map(|line| {
+        let title = line.strip_prefix("## --- ")?.strip_suffix(" ---")?;
+        Some(title.to_string())
+    })
+}
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+fn read_title(puzzle: PuzzleId) -> Option<String> {
+    parse_title(&fs::read_to_string(aoc_client::get_puzzle_path(puzzle)).ok()?)
+}
+
+/// The parts of a puzzle page that a new solution is pre-filled with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchedPuzzle {
+    /// The puzzle description in Markdown.
+    pub description: String,
+    pub example: Option<String>,
+    pub example_answers: [Option<String>; 2],
+}
+
+/// Extracts the example and its expected answers from a puzzle page.
+/// The example is the first code block of the page. The expected answer of a part is the last
+/// emphasized code of its description, e.g. `<code><em>142</em></code>`. Part two often comes with
+/// an example of its own, in which case its answer does not apply to the first example.
+#[must_use]
+pub fn extract_puzzle(page: &str) -> FetchedPuzzle {
+    let document = html::parse(page);
+    let articles = document.find_all("article");
+
+    let example = document.find_all("pre").first().map(|pre| {
+        pre.find_all("code")
+            .first()
+            .map_or_else(|| pre.text(), |code| code.text())
+    });
+
+    let answer = |article: &html::Element| {
+        article
+            .find_all("code")
+            .into_iter()
+            .rfind(|code| !code.find_all("em").is_empty())
+            .map(|code| code.text().trim().to_string())
+    };
+
+    let part_one = articles.first().and_then(|article| answer(article));
+    let part_two = articles
+        .get(1)
+        .filter(|article| article.find_all("pre").is_empty())
+        .and_then(|article| answer(article));
+
+    FetchedPuzzle {
+        description: html::to_markdown(page),
+        example,
+        example_answers: [part_one, part_two],
     }
 }
-"#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn fetch(puzzle: PuzzleId) -> Result<FetchedPuzzle, AocClientError> {
+    let page = AocClient::from_env().get_puzzle(puzzle)?;
+    Ok(extract_puzzle(&page))
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn read_template() -> Result<String, std::io::Error> {
+    match fs::read_to_string(TEMPLATE_PATH) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DEFAULT_TEMPLATE.to_string()),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Keeps or removes the `{{#name}}...{{/name}}` sections of a template.
+/// Tags that are alone on their line are removed along with the line.
+fn render_sections(template: &str, sections: &[(&str, bool)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{#") {
+        let Some(name_end) = rest[start..].find("}}").map(|i| start + i) else {
+            break;
+        };
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+        let name = &rest[start + 3..name_end];
+        let closing = format!("{{{{/{name}}}}}");
+
+        let Some(close_start) = rest[name_end..].find(&closing).map(|i| name_end + i) else {
+            break;
+        };
+
+        let is_standalone = |tag_start: usize, tag_end: usize| {
+            (tag_start == 0 || rest[..tag_start].ends_with('\n'))
+                && rest[tag_end..].starts_with('\n')
+        };
+
+        let mut inner_start = name_end + 2;
+        if is_standalone(start, inner_start) {
+            inner_start += 1;
+        }
+
+        let mut after = close_start + closing.len();
+        if is_standalone(close_start, after) {
+            after += 1;
         }
+
+        out.push_str(&rest[..start]);
+
+        if sections.contains(&(name, true)) {
+            out.push_str(&render_sections(&rest[inner_start..close_start], sections));
+        }
+
+        rest = &rest[after..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Returns the expression of an expected answer in a test, or [`None`] if the answer does not fit the
+/// answer type, e.g. a string answer for a `u32` part.
+fn answer_literal(answer: &str, answer_type: &str) -> Option<String> {
+    let number = |fits: bool| fits.then(|| answer.to_string());
+
+    match answer_type {
+        "String" => Some(format!("{answer:?}.to_string()")),
+        "&str" | "&'static str" => Some(format!("{answer:?}")),
+        "char" => answer.parse::<char>().ok().map(|c| format!("{c:?}")),
+        "u8" => number(answer.parse::<u8>().is_ok()),
+        "u16" => number(answer.parse::<u16>().is_ok()),
+        "u32" => number(answer.parse::<u32>().is_ok()),
+        "u64" => number(answer.parse::<u64>().is_ok()),
+        "u128" => number(answer.parse::<u128>().is_ok()),
+        "usize" => number(answer.parse::<usize>().is_ok()),
+        "i8" => number(answer.parse::<i8>().is_ok()),
+        "i16" => number(answer.parse::<i16>().is_ok()),
+        "i32" => number(answer.parse::<i32>().is_ok()),
+        "i64" => number(answer.parse::<i64>().is_ok()),
+        "i128" => number(answer.parse::<i128>().is_ok()),
+        "isize" => number(answer.parse::<isize>().is_ok()),
+        _ => None,
+    }
+}
+
+/// Renders a solution template, see [`TEMPLATE_PATH`] for the placeholders.
+#[must_use]
+pub fn render(
+    template: &str,
+    puzzle: PuzzleId,
+    options: &ScaffoldOptions,
+    context: &TemplateContext,
+) -> String {
+    let day = puzzle.day.into_inner().to_string();
+
+    let title = context
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {day}"));
+
+    let (input, example) = if options.grid {
+        (
+            "&DenseGrid<char>",
+            "&parse(&advent_of_code::template::read_file(\"examples\", PUZZLE))",
+        )
+    } else {
+        (
+            "&str",
+            "&advent_of_code::template::read_file(\"examples\", PUZZLE)",
+        )
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    let answer = |answer: &Option<String>| {
+        answer
+            .as_deref()
+            .and_then(|answer| answer_literal(answer, &options.answer_type))
+            .map_or("None".to_string(), |answer| format!("Some({answer})"))
+    };
+
+    render_sections(
+        template,
+        &[("grid", options.grid), ("regex", options.regex)],
+    )
+    .replace("{{year}}", &puzzle.year.to_string())
+    .replace("{{day}}", &day)
+    .replace("{{title}}", &title)
+    .replace("{{type}}", &options.answer_type)
+    .replace("{{input}}", input)
+    .replace("{{example}}", example)
+    .replace("{{part_one_answer}}", &answer(&context.example_answers[0]))
+    .replace("{{part_two_answer}}", &answer(&context.example_answers[1]))
+}
+
+/// What scaffolding does with a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Skip,
+    Overwrite,
+}
+
+impl Action {
+    /// Decides what to do with a file. Files without contents are only created as empty placeholders
+    /// and never replace an existing file, even with `--force`.
+    #[must_use]
+    pub fn plan(exists: bool, has_contents: bool, force: bool) -> Self {
+        match (exists, has_contents && force) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Overwrite,
+            (true, false) => Action::Skip,
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    }
+
+    fn describe(self, dry_run: bool) -> &'static str {
+        match (self, dry_run) {
+            (Action::Create, false) => "Created",
+            (Action::Create, true) => "Would create",
+            (Action::Skip, false) => "Skipped existing",
+            (Action::Skip, true) => "Would skip existing",
+            (Action::Overwrite, false) => "Overwrote",
+            (Action::Overwrite, true) => "Would overwrite",
+        }
+    }
+}
+
+/// A file that scaffolding writes, e.g. the module or the example input.
+struct PlannedFile {
+    kind: &'static str,
+    path: String,
+    /// The contents of the file, or [`None`] for an empty placeholder.
+    contents: Option<String>,
+    action: Action,
+    /// The contents are downloaded from the puzzle page.
+    is_downloaded: bool,
+}
+
+impl PlannedFile {
+    fn new(kind: &'static str, path: String, contents: Option<String>, force: bool) -> Self {
+        let action = Action::plan(Path::new(&path).exists(), contents.is_some(), force);
+
+        PlannedFile {
+            kind,
+            path,
+            contents,
+            action,
+            is_downloaded: false,
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    /// Plans a file whose contents are downloaded. A dry run does not download the puzzle, so the
+    /// contents are not known.
+    fn downloaded(
+        kind: &'static str,
+        path: String,
+        contents: Option<String>,
+        options: &ScaffoldOptions,
+    ) -> Self {
+        let contents = if options.dry_run {
+            Some(String::new())
+        } else {
+            contents
+        };
+
+        PlannedFile {
+            is_downloaded: true,
+            ..PlannedFile::new(kind, path, contents, options.force)
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    fn write(&self) -> Result<(), std::io::Error> {
+        if self.action == Action::Skip {
+            return Ok(());
         }
+
+        create_parent_dir(&self.path)?;
+        fs::write(&self.path, self.contents.as_deref().unwrap_or_default())
+    }
+}
+
+/// What scaffolding does with the `[[bin]]` entry of a solution in `Cargo.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinEntry {
+    Present,
+    Missing,
+    /// The entry points to another path.
+    Outdated {
+        path: String,
+    },
+}
+
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+/// Looks up the `[[bin]]` entry of a solution in a manifest.
+pub fn find_bin_entry(
+    manifest: &str,
+    puzzle: PuzzleId,
+    module_path: &str,
+) -> Result<BinEntry, toml::de::Error> {
+    let manifest: toml::Table = manifest.parse()?;
+    let name = puzzle.to_string();
+
+    let entry = manifest
+        .get("bin")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .find(|bin| bin.get("name").and_then(toml::Value::as_str) == Some(&name));
+
+    Ok(
+        match entry.map(|bin| bin.get("path").and_then(toml::Value::as_str)) {
+            None => BinEntry::Missing,
+            Some(Some(path)) if path == module_path => BinEntry::Present,
+            Some(path) => BinEntry::Outdated {
+                path: path.unwrap_or_default().to_string(),
+            },
+        },
+    )
+}
+
+/// Registers the solution binary in a manifest, since per-year bins are not discovered automatically.
+/// An existing entry is updated in place to keep the rest of the manifest as it is.
+#[must_use]
+pub fn update_bin_entry(manifest: &str, puzzle: PuzzleId, module_path: &str) -> String {
+    let name_line = format!("name = \"{puzzle}\"");
+    let path_line = format!("path = \"{module_path}\"");
+
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    let Some(name_index) = lines.iter().position(|line| line.trim() == name_line) else {
+        let separator = if manifest.is_empty() || manifest.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        return format!("{manifest}{separator}\n[[bin]]\n{name_line}\n{path_line}\n");
+    };
+
+    let mut updated: Vec<String> = lines.iter().map(ToString::to_string).collect();
+
+    // the entry ends at the next table.
+    let entry_end = lines[name_index + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| name_index + 1 + i);
+
+    match (name_index + 1..entry_end).find(|&i| lines[i].trim_start().starts_with("path")) {
+        Some(i) => updated[i] = path_line,
+        None => updated.insert(name_index + 1, path_line),
+    }
+
+    format!("{}\n", updated.join("\n"))
+}
+
+pub fn handle(puzzle: PuzzleId, options: &ScaffoldOptions) -> Result<(), Error> {
+    let input_path = get_data_path("inputs", puzzle, "txt");
+    let example_path = get_data_path("examples", puzzle, "txt");
+    let module_path = get_path_for_bin(puzzle);
+
+    let template = read_template()
+        .map_err(|e| Error::Failed(format!("could not read template \"{TEMPLATE_PATH}\": {e}")))?;
+
+    // a dry run only plans the downloaded files.
+    let fetched = if options.fetch && !options.dry_run {
+        Some(fetch(puzzle)?)
+    } else {
+        None
+    };
+
+    let context = match &fetched {
+        Some(fetched) => TemplateContext {
+            title: parse_title(&fetched.description),
+            example_answers: fetched.example_answers.clone(),
+        },
+        None => TemplateContext {
+            title: read_title(puzzle),
+            ..TemplateContext::default()
+        },
+    };
+
+    let module = render(&template, puzzle, options, &context);
+
+    let mut files = vec![PlannedFile::new(
+        "module file",
+        module_path.clone(),
+        Some(module),
+        options.force,
+    )];
+
+    if options.fetch {
+        files.push(PlannedFile::downloaded(
+            "puzzle description",
+            aoc_client::get_puzzle_path(puzzle),
+            fetched.as_ref().map(|fetched| fetched.description.clone()),
+            options,
+        ));
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    files.push(PlannedFile::new(
+        "input file",
+        input_path,
+        None,
+        options.force,
+    ));
+
+    let example = fetched.and_then(|fetched| fetched.example);
+    files.push(if options.fetch {
+        PlannedFile::downloaded("example file", example_path, example, options)
+    } else {
+        PlannedFile::new("example file", example_path, example, options.force)
+    });
+
+    let manifest = fs::read_to_string("Cargo.toml")
+        .map_err(|e| Error::Failed(format!("could not read Cargo.toml: {e}")))?;
+
+    let bin_entry = find_bin_entry(&manifest, puzzle, &module_path)
+        .map_err(|e| Error::Failed(format!("could not parse Cargo.toml: {e}")))?;
+
+    for file in &files {
+        if !options.dry_run {
+            file.write().map_err(|e| {
+                Error::Failed(format!(
+                    "could not write {} \"{}\": {e}",
+                    file.kind, file.path
+                ))
+            })?;
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+
+        let empty = if file.contents.is_none() && file.action != Action::Skip {
+            "empty "
+        } else {
+            ""
+        };
+
+        if options.dry_run && file.is_downloaded && file.action != Action::Skip {
+            println!("Would download {} to \"{}\"", file.kind, file.path);
+            continue;
+        }
+
+        println!(
+            "{} {empty}{} \"{}\"",
+            file.action.describe(options.dry_run),
+            file.kind,
+            file.path
+        );
+    }
+
+    let verb = match (&bin_entry, options.dry_run) {
+        (BinEntry::Present, _) => None,
+        (BinEntry::Missing, false) => Some("Added"),
+        (BinEntry::Missing, true) => Some("Would add"),
+        (BinEntry::Outdated { .. }, false) => Some("Updated"),
+        (BinEntry::Outdated { .. }, true) => Some("Would update"),
+    };
+
+    match verb {
+        None => println!("Skipped existing binary \"{puzzle}\" in \"Cargo.toml\""),
+        Some(verb) => {
+            if !options.dry_run {
+                let updated = update_bin_entry(&manifest, puzzle, &module_path);
+
+                fs::write("Cargo.toml", updated)
+                    .map_err(|e| Error::Failed(format!("could not update Cargo.toml: {e}")))?;
+            }
+
+            match bin_entry {
+                BinEntry::Outdated { path } => println!(
+                    "{verb} binary \"{puzzle}\" in \"Cargo.toml\" from \"{path}\" to \"{module_path}\""
+                ),
+                _ => println!("{verb} binary \"{puzzle}\" to \"Cargo.toml\""),
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+
+    if options.dry_run {
+        println!("Dry run, no files were changed.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            puzzle.day, puzzle.year
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::ErrorKind;
+    use std::net::TcpListener;
+    use std::{env, fs, path::Path};
+
+    use super::{
+        extract_puzzle, find_bin_entry, handle, parse_title, render, render_sections,
+        update_bin_entry, Action, BinEntry, ScaffoldOptions, TemplateContext, DEFAULT_TEMPLATE,
+    };
+    use crate::template::{aoc_client, get_data_path, get_path_for_bin};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn renders_sections() {
+        let template = "a\n{{#x}}\nb\n{{/x}}\nc{{#y}}, d{{/y}}\n";
+        assert_eq!(render_sections(template, &[("x", true)]), "a\nb\nc\n");
+        assert_eq!(render_sections(template, &[("y", true)]), "a\nc, d\n");
+    }
+
+    #[test]
+    fn renders_default_template() {
+        let puzzle = PuzzleId::new(year!(2023), day!(5));
+        let context = TemplateContext {
+            title: Some("Day 5: If You Give A Seed A Fertilizer".into()),
+            example_answers: [Some("35".into()), None],
+        };
+
+        let module = render(
+            DEFAULT_TEMPLATE,
+            puzzle,
+            &ScaffoldOptions::default(),
+            &context,
+        );
+
+        assert!(module.starts_with(
+            "//! Day 5: If You Give A Seed A Fertilizer\n\nadvent_of_code::solution!(2023, 5);\n"
+        ));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u32> {"));
+        assert!(module.contains("assert_eq!(result, Some(35));"));
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn renders_grid_and_regex() {
+        let puzzle = PuzzleId::new(year!(2023), day!(14));
+        let options = ScaffoldOptions {
+            answer_type: "u64".into(),
+            grid: true,
+            regex: true,
+            ..ScaffoldOptions::default()
+        };
+
+        let module = render(
+            DEFAULT_TEMPLATE,
+            puzzle,
+            &options,
+            &TemplateContext::default(),
+        );
+
+        assert!(module.starts_with("//! Day 14\nuse lazy_static::lazy_static;\n"));
+        assert!(module.contains("advent_of_code::solution!(2023, 14, parse);"));
+        assert!(module.contains("static ref LINE_RE: Regex"));
+        assert!(module.contains("pub fn part_two(input: &DenseGrid<char>) -> Option<u64> {"));
+        assert!(module.contains("part_one(&parse(&advent_of_code::template::read_file("));
+    }
+
+    #[test]
+    fn renders_only_answers_of_the_answer_type() {
+        let puzzle = PuzzleId::new(year!(2016), day!(5));
+        let context = TemplateContext {
+            title: None,
+            example_answers: [Some("18f47a30".into()), Some("-3".into())],
+        };
+        let render_as = |answer_type: &str| {
+            let options = ScaffoldOptions {
+                answer_type: answer_type.into(),
+                ..ScaffoldOptions::default()
+            };
+            render(DEFAULT_TEMPLATE, puzzle, &options, &context)
+        };
+
+        let module = render_as("u32");
+        assert!(!module.contains("Some("));
+
+        let module = render_as("i64");
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(module.contains("assert_eq!(result, Some(-3));"));
+
+        let module = render_as("String");
+        assert!(module.contains("assert_eq!(result, Some(\"18f47a30\".to_string()));"));
+    }
+
+    #[test]
+    fn extracts_example_and_answers() {
+        let fetched = extract_puzzle(include_str!("../fixtures/puzzle_2023_06.html"));
+
+        assert_eq!(
+            fetched.example.as_deref(),
+            Some("Time:      7  15   30\nDistance:  9  40  200\n")
+        );
+        assert_eq!(
+            fetched.example_answers,
+            [Some("288".into()), Some("71503".into())]
+        );
+        assert_eq!(
+            parse_title(&fetched.description).as_deref(),
+            Some("Day 6: Wait For It")
+        );
+    }
+
+    #[test]
+    fn skips_answers_of_other_examples() {
+        let fetched = extract_puzzle(include_str!("../fixtures/puzzle_2023_01.html"));
+
+        assert_eq!(
+            fetched.example.as_deref(),
+            Some("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n")
+        );
+        assert_eq!(fetched.example_answers, [Some("142".into()), None]);
+        assert!(fetched.description.contains("## --- Part Two ---"));
+    }
+
+    #[test]
+    fn plans_file_actions() {
+        assert_eq!(Action::plan(false, false, false), Action::Create);
+        assert_eq!(Action::plan(true, true, false), Action::Skip);
+        assert_eq!(Action::plan(true, true, true), Action::Overwrite);
+        // inputs are never replaced by an empty file.
+        assert_eq!(Action::plan(true, false, true), Action::Skip);
+    }
+
+    #[test]
+    fn dry_runs_do_not_download_or_write() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        env::set_var(
+            "AOC_BASE_URL",
+            format!("http://{}", listener.local_addr().unwrap()),
+        );
+
+        let puzzle = PuzzleId::new(year!(2015), day!(25));
+        let manifest = fs::read_to_string("Cargo.toml").unwrap();
+        let options = ScaffoldOptions {
+            fetch: true,
+            dry_run: true,
+            ..ScaffoldOptions::default()
+        };
+
+        assert!(handle(puzzle, &options).is_ok());
+        assert_eq!(
+            listener.accept().map_err(|e| e.kind()).err(),
+            Some(ErrorKind::WouldBlock)
+        );
+        assert_eq!(fs::read_to_string("Cargo.toml").unwrap(), manifest);
+
+        for path in [
+            get_path_for_bin(puzzle),
+            aoc_client::get_puzzle_path(puzzle),
+            get_data_path("inputs", puzzle, "txt"),
+            get_data_path("examples", puzzle, "txt"),
+        ] {
+            assert!(!Path::new(&path).exists(), "{path} was written");
+        }
+    }
+
+    const MANIFEST: &str = "[package]\nname = \"advent_of_code\"\n\n[[bin]]\nname = \"2023-01\"\npath = \"src/bin/01.rs\"\n\n[[bin]]\nname = \"2023-02\"\npath = \"src/bin/2023/02.rs\"\n";
+
+    #[test]
+    fn finds_bin_entries() {
+        let entry = |day| {
+            let puzzle = PuzzleId::new(year!(2023), day);
+            find_bin_entry(MANIFEST, puzzle, &format!("src/bin/2023/{}.rs", puzzle.day)).unwrap()
+        };
+
+        assert_eq!(
+            entry(day!(1)),
+            BinEntry::Outdated {
+                path: "src/bin/01.rs".into()
+            }
+        );
+        assert_eq!(entry(day!(2)), BinEntry::Present);
+        assert_eq!(entry(day!(3)), BinEntry::Missing);
+    }
+
+    #[test]
+    fn updates_bin_entries() {
+        let updated = update_bin_entry(
+            MANIFEST,
+            PuzzleId::new(year!(2023), day!(1)),
+            "src/bin/2023/01.rs",
+        );
+        assert_eq!(
+            updated,
+            MANIFEST.replace("src/bin/01.rs", "src/bin/2023/01.rs")
+        );
+
+        let added = update_bin_entry(
+            MANIFEST,
+            PuzzleId::new(year!(2023), day!(3)),
+            "src/bin/2023/03.rs",
+        );
+        assert_eq!(
+            added,
+            format!("{MANIFEST}\n[[bin]]\nname = \"2023-03\"\npath = \"src/bin/2023/03.rs\"\n")
+        );
+    }
 }
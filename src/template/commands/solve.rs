@@ -1,31 +1,47 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
-
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use crate::template::runner::{InputSource, RunOptions};
+use crate::template::Error;
+use crate::PuzzleId;
+
+/// The `cargo run` command that runs the solution binary of a puzzle.
+#[must_use]
+pub fn command(
+    puzzle: PuzzleId,
+    release: bool,
+    options: &RunOptions,
+    input: &InputSource,
+) -> Command {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.append(&mut options.to_args());
+    cmd_args.append(&mut input.to_args());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
-
-    if time {
-        cmd_args.push("--time".to_string());
-    }
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args);
+    cmd
+}
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    options: &RunOptions,
+    input: &InputSource,
+) -> Result<(), Error> {
+    let status = command(puzzle, release, options, input)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()?;
+
+    // the solution already printed why it failed.
+    if !status.success() {
+        return Err(Error::Failed(format!("{puzzle} failed with {status}.")));
+    }
 
-    cmd.wait().unwrap();
+    Ok(())
 }
@@ -0,0 +1,84 @@
+use std::{fs, path::Path};
+
+use crate::template::aoc_client::AocClient;
+use crate::template::progress::{self, format_stars, Stars};
+use crate::template::Error;
+use crate::{all_days, Year};
+
+/// Reads a private leaderboard from a JSON file, or downloads it if `source` is a leaderboard id.
+fn read_leaderboard(year: Year, source: &str) -> Result<String, Error> {
+    match source.parse() {
+        Ok(id) if !Path::new(source).is_file() => {
+            Ok(AocClient::from_env().get_leaderboard(year, id)?)
+        }
+        _ => fs::read_to_string(source)
+            .map_err(|e| Error::Failed(format!("could not read leaderboard \"{source}\": {e}"))),
+    }
+}
+
+fn last_day(stars: &[&Stars]) -> u8 {
+    stars
+        .iter()
+        .filter_map(|stars| stars.keys().last())
+        .map(|day| day.into_inner())
+        .max()
+        .unwrap_or(1)
+}
+
+/// Show the days solved locally, optionally the stars on the site and the ranking of a private leaderboard.
+pub fn handle(year: Year, online: bool, leaderboard: Option<&str>) -> Result<(), Error> {
+    let local = progress::local_stars(year)?;
+
+    let site = if online {
+        let page = AocClient::from_env().get_calendar(year)?;
+        Some(progress::parse_calendar(&page))
+    } else {
+        None
+    };
+
+    let last = last_day(
+        &[Some(&local), site.as_ref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>(),
+    );
+
+    println!("Day  Local{}", if site.is_some() { "  Site" } else { "" });
+
+    for day in all_days().take(last.into()) {
+        let count = |stars: &Stars| stars.get(&day).copied().unwrap_or_default();
+
+        match &site {
+            Some(site) => println!(
+                " {day}  {}     {}",
+                format_stars(count(&local)),
+                format_stars(count(site))
+            ),
+            None => println!(" {day}  {}", format_stars(count(&local))),
+        }
+    }
+
+    let total = |stars: &Stars| stars.values().map(|&count| u32::from(count)).sum::<u32>();
+
+    match &site {
+        Some(site) => println!(
+            "\nTotal: {} star(s) solved locally, {} on the site.",
+            total(&local),
+            total(site)
+        ),
+        None => println!("\nTotal: {} star(s) solved locally.", total(&local)),
+    }
+
+    let Some(source) = leaderboard else {
+        return Ok(());
+    };
+
+    let json = read_leaderboard(year, source)?;
+    let ranking = progress::parse_leaderboard(&json)?;
+
+    let days: Vec<&Stars> = ranking.iter().map(|member| &member.days).collect();
+
+    println!();
+    println!("{}", progress::format_ranking(&ranking, last_day(&days)));
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+use crate::template::vault::{self, Key};
+use crate::template::{get_data_dir, Error as TemplateError};
+use crate::{all_days, PuzzleId, Year};
+
+/// What `cargo vault` does with the inputs of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Encrypt the plain text inputs and remove them.
+    Lock,
+    /// Decrypt the locked inputs to plain text files.
+    Unlock,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Action::Lock => "lock",
+            Action::Unlock => "unlock",
+        })
+    }
+}
+
+/// Reads the vault key. Locking creates a key if there is none yet.
+fn read_key(action: Action) -> Result<Key, vault::Error> {
+    match Key::read() {
+        Err(vault::Error::KeyNotFound) if action == Action::Lock => {
+            let key = Key::generate();
+            let path = key.write()?;
+            println!(
+                "🔑 Created a new vault key in \"{}\". Back it up, locked inputs can not be read without it.",
+                path.display()
+            );
+            Ok(key)
+        }
+        result => result,
+    }
+}
+
+pub fn handle(year: Year, action: Action) -> Result<(), TemplateError> {
+    let key = read_key(action)?;
+
+    let mut count = 0;
+    let mut has_errors = false;
+
+    for day in all_days() {
+        let puzzle = PuzzleId::new(year, day);
+
+        let result = match action {
+            Action::Lock => vault::lock(puzzle, &key),
+            Action::Unlock => vault::unlock(puzzle, &key),
+        };
+
+        match result {
+            Ok(true) => count += 1,
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Could not {action} the input of {puzzle}: {e}");
+                has_errors = true;
+            }
+        }
+    }
+
+    match action {
+        Action::Lock => println!(
+            "🔒 Locked {count} input(s) in \"{}\".",
+            vault::get_vault_dir(year, &key)
+        ),
+        Action::Unlock => println!(
+            "🔓 Unlocked {count} input(s) to \"{}/{year}/inputs\".",
+            get_data_dir()
+        ),
+    }
+
+    if has_errors {
+        return Err(TemplateError::Failed(format!(
+            "some inputs could not be {action}ed."
+        )));
+    }
+
+    Ok(())
+}
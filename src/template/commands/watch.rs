@@ -0,0 +1,279 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Stdio,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    answers::get_answers_path,
+    commands::solve,
+    examples::get_examples_dir,
+    get_data_path, get_path_for_bin,
+    report::{self, Format, Record},
+    runner::{format_part, InputSource, RunOptions},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
+
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The answer and timing of one part of a run, read from the JSON report of the solution binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartSummary {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub verdict: String,
+    pub mean: Duration,
+}
+
+impl From<Record> for PartSummary {
+    fn from(record: Record) -> Self {
+        PartSummary {
+            part: record.part,
+            answer: record.answer.filter(|_| record.status == "success"),
+            verdict: record.verdict,
+            mean: Duration::from_nanos(u64::try_from(record.mean_ns).unwrap_or(u64::MAX)),
+        }
+    }
+}
+
+/// Reads the JSON report of a run, see [`report::to_json`]. A missing or invalid report has no parts.
+#[must_use]
+pub fn parse_report(json: &str) -> Vec<PartSummary> {
+    report::from_json(json.trim())
+        .map(|records| records.into_iter().map(PartSummary::from).collect())
+        .unwrap_or_default()
+}
+
+/// Formats a part of the current run, along with how it changed since the previous run.
+#[must_use]
+pub fn format_change(current: &PartSummary, previous: Option<&PartSummary>) -> String {
+    let answer = match &current.answer {
+        Some(answer) if answer.contains('\n') => format!("▼\n{answer}"),
+        Some(answer) => format!("{ANSI_BOLD}{answer}{ANSI_RESET}"),
+        None if current.part == 0 => String::new(),
+        None => "✖".into(),
+    };
+
+    let verdict = match current.verdict.as_str() {
+        "correct" => " ✔",
+        "incorrect" => " ✘",
+        _ => "",
+    };
+
+    let was = match previous {
+        Some(previous) if previous.answer != current.answer => format!(
+            " {ANSI_ITALIC}(was {}){ANSI_RESET}",
+            previous
+                .answer
+                .as_deref()
+                .map_or("nothing".to_string(), |a| if a.contains('\n') {
+                    "a different answer".to_string()
+                } else {
+                    a.to_string()
+                })
+        ),
+        _ => String::new(),
+    };
+
+    let delta = previous
+        .filter(|previous| !previous.mean.is_zero())
+        .map(|previous| {
+            let percent = (current.mean.as_secs_f64() / previous.mean.as_secs_f64() - 1.0) * 100.0;
+            format!(
+                ", {:.1?} → {:.1?} {percent:+.1}%",
+                previous.mean, current.mean
+            )
+        })
+        .unwrap_or_default();
+
+    let mut part = format_part(current.part);
+    part[..1].make_ascii_uppercase();
+
+    let (answer, rest) = match answer.split_once('\n') {
+        Some((marker, rest)) => (marker.to_string(), format!("\n{rest}")),
+        None => (answer, String::new()),
+    };
+
+    let separator = if answer.is_empty() { "" } else { " " };
+
+    format!(
+        "{part}:{separator}{answer}{verdict}{was} ({:.1?}{delta}){rest}",
+        current.mean
+    )
+}
+
+/// The files that trigger a re-run: the solution, the shared utilities and the data of the day.
+fn watched_paths(puzzle: PuzzleId, input: &InputSource) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = [
+        get_path_for_bin(puzzle),
+        "src/utils".to_string(),
+        "src/utils.rs".to_string(),
+        get_data_path("inputs", puzzle, "txt"),
+        get_data_path("examples", puzzle, "txt"),
+        get_examples_dir(puzzle),
+        get_answers_path(puzzle),
+    ]
+    .into_iter()
+    .map(PathBuf::from)
+    .collect();
+
+    if let InputSource::File(path) = input {
+        paths.push(PathBuf::from(path));
+    }
+
+    paths
+}
+
+/// The modification times of all files below the watched paths. Missing paths are skipped, so
+/// creating them counts as a change.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    fn visit(path: &Path, out: &mut BTreeMap<PathBuf, SystemTime>) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), out);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            out.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    for path in paths {
+        visit(path, &mut out);
+    }
+    out
+}
+
+/// Runs the solution binary once and reads its results. The solution prints nothing itself,
+/// compiler errors and panics still show up on stderr.
+fn run(
+    puzzle: PuzzleId,
+    release: bool,
+    options: &RunOptions,
+    input: &InputSource,
+) -> Option<Vec<PartSummary>> {
+    let options = RunOptions {
+        format: Format::Json,
+        ..*options
+    };
+
+    let output = solve::command(puzzle, release, &options, input)
+        .env("CARGO_TERM_QUIET", "true")
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+
+    // failing examples still report their results.
+    let results = parse_report(&String::from_utf8_lossy(&output.stdout));
+
+    if results.is_empty() && !output.status.success() {
+        None
+    } else {
+        Some(results)
+    }
+}
+
+/// Run a solution whenever its source, the shared utilities or its data change, until interrupted.
+pub fn handle(puzzle: PuzzleId, release: bool, options: &RunOptions, input: &InputSource) -> ! {
+    let paths = watched_paths(puzzle, input);
+    let mut files = snapshot(&paths);
+    let mut previous: Option<Vec<PartSummary>> = None;
+
+    loop {
+        println!("{ANSI_BOLD}Running {puzzle}...{ANSI_RESET}");
+
+        match run(puzzle, release, options, input) {
+            Some(current) => {
+                for (i, result) in current.iter().enumerate() {
+                    let before = previous
+                        .as_ref()
+                        .and_then(|previous| previous.get(i))
+                        .filter(|before| before.part == result.part);
+
+                    println!("{}", format_change(result, before));
+                }
+
+                previous = Some(current);
+            }
+            None => eprintln!("The run failed, fix the error to try again."),
+        }
+
+        println!("{ANSI_ITALIC}Waiting for changes...{ANSI_RESET}\n");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&paths);
+
+            if current != files {
+                // editors often write files in several steps.
+                thread::sleep(POLL_INTERVAL);
+                files = snapshot(&paths);
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_change, parse_report, PartSummary};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    fn summary(part: u8, answer: Option<&str>, mean: u64) -> PartSummary {
+        PartSummary {
+            part,
+            answer: answer.map(Into::into),
+            verdict: "unknown".into(),
+            mean: Duration::from_micros(mean),
+        }
+    }
+
+    #[test]
+    fn parses_reports() {
+        let json = r##"[{"year":2023,"day":5,"part":1,"answer":"35","status":"success","verdict":"correct","samples":1,"min_ns":1000,"mean_ns":1000,"median_ns":1000,"p95_ns":1000,"max_ns":1000,"std_dev_ns":0,"outliers":0},{"year":2023,"day":5,"part":2,"answer":"#.\n.#","status":"success","verdict":"unknown","samples":1,"min_ns":2000,"mean_ns":2000,"median_ns":2000,"p95_ns":2000,"max_ns":2000,"std_dev_ns":0,"outliers":0},{"year":2023,"day":5,"part":2,"answer":null,"status":"failure","verdict":"unknown","samples":1,"min_ns":3000,"mean_ns":3000,"median_ns":3000,"p95_ns":3000,"max_ns":3000,"std_dev_ns":0,"outliers":0}]
+"##;
+
+        let summaries = parse_report(json);
+
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(summaries[0].answer.as_deref(), Some("35"));
+        assert_eq!(summaries[0].verdict, "correct");
+        assert_eq!(summaries[0].mean, Duration::from_micros(1));
+        assert_eq!(summaries[1].answer.as_deref(), Some("#.\n.#"));
+        assert_eq!(summaries[2].answer, None);
+        assert!(parse_report("Part 1: 35").is_empty());
+    }
+
+    #[test]
+    fn formats_changes() {
+        assert_eq!(
+            format_change(&summary(1, Some("35"), 8), None),
+            format!("Part 1: {ANSI_BOLD}35{ANSI_RESET} (8.0µs)")
+        );
+        assert_eq!(
+            format_change(
+                &summary(2, Some("46"), 12),
+                Some(&summary(2, Some("45"), 8))
+            ),
+            format!(
+                "Part 2: {ANSI_BOLD}46{ANSI_RESET} {ANSI_ITALIC}(was 45){ANSI_RESET} (12.0µs, 8.0µs → 12.0µs +50.0%)"
+            )
+        );
+        assert_eq!(
+            format_change(&summary(0, None, 4), Some(&summary(0, None, 8))),
+            "Parse: (4.0µs, 8.0µs → 4.0µs -50.0%)"
+        );
+    }
+}
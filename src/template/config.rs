@@ -0,0 +1,157 @@
+/// Module that reads the project config `aoc.toml`. It supplies defaults for the options that are not
+/// passed on the command line, e.g.:
+///
+/// ```toml
+/// year = 2023
+/// release = true
+/// time = false
+/// format = "text"
+/// data_dir = "data"
+/// ```
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::{fs, io};
+
+use crate::template::report::Format;
+use crate::Year;
+
+/// The path of the config, relative to the root of the project.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse {CONFIG_PATH}: {e}"),
+            Error::IO(e) => write!(f, "could not read {CONFIG_PATH}: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Clone for Error {
+    fn clone(&self) -> Self {
+        match self {
+            Error::Parser(e) => Error::Parser(e.clone()),
+            Error::IO(e) => Error::IO(io::Error::new(e.kind(), e.to_string())),
+        }
+    }
+}
+
+/// The defaults of the project. Options that are not set fall back to the defaults of the commands.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The year of `--year`, used when `AOC_YEAR` is not set either.
+    pub year: Option<Year>,
+    /// Build solutions in release mode, and update the README and benchmark history with `--time`.
+    pub release: Option<bool>,
+    /// Benchmark solutions, as with `--time`.
+    pub time: Option<bool>,
+    pub format: Option<Format>,
+    /// The directory of inputs, examples and other data, used when `AOC_DATA_DIR` is not set.
+    pub data_dir: Option<String>,
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let table: toml::Table = s.parse().map_err(|e| Error::Parser(format!("{e}")))?;
+        let mut config = Config::default();
+
+        for (key, value) in &table {
+            let invalid = |expected: &str| Error::Parser(format!("`{key}` should be {expected}."));
+            let boolean = || value.as_bool().ok_or_else(|| invalid("a boolean"));
+
+            match key.as_str() {
+                "year" => {
+                    let year = value
+                        .as_integer()
+                        .and_then(|year| u16::try_from(year).ok())
+                        .and_then(Year::new)
+                        .ok_or_else(|| invalid("a year of 2015 or later"))?;
+                    config.year = Some(year);
+                }
+                "release" => config.release = Some(boolean()?),
+                "time" => config.time = Some(boolean()?),
+                "format" => {
+                    let format = value
+                        .as_str()
+                        .and_then(|format| format.parse().ok())
+                        .ok_or_else(|| invalid("one of `text`, `json` or `csv`"))?;
+                    config.format = Some(format);
+                }
+                "data_dir" => {
+                    let data_dir = value.as_str().ok_or_else(|| invalid("a string"))?;
+                    config.data_dir = Some(data_dir.to_string());
+                }
+                _ => return Err(Error::Parser(format!("unknown key `{key}`."))),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the config of the project. A missing file means that all defaults apply.
+    pub fn read() -> Result<Self, Error> {
+        match fs::read_to_string(CONFIG_PATH) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Returns the config of the project, which is read once.
+pub fn get() -> Result<&'static Config, Error> {
+    static CONFIG: OnceLock<Result<Config, Error>> = OnceLock::new();
+    CONFIG
+        .get_or_init(Config::read)
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Config;
+    use crate::template::report::Format;
+    use crate::year;
+
+    #[test]
+    fn parses_config() {
+        let config = Config::parse(
+            "year = 2022\nrelease = true\ntime = false\nformat = \"csv\"\ndata_dir = \"puzzles\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(year!(2022)),
+                release: Some(true),
+                time: Some(false),
+                format: Some(Format::Csv),
+                data_dir: Some("puzzles".into()),
+            }
+        );
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(Config::parse("year = 2014\n").is_err());
+        assert!(Config::parse("year = \"2023\"\n").is_err());
+        assert!(Config::parse("format = \"xml\"\n").is_err());
+        assert!(Config::parse("jobs = 4\n").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+/// Module with the error that the commands of the template return, so that they can be composed and
+/// called from tests or other tools. The binaries print it and exit with a non-zero status.
+use std::fmt::Display;
+use std::io;
+
+use crate::template::aoc_client::AocClientError;
+use crate::template::commands::all;
+use crate::template::inputs::{self, Problem};
+use crate::template::{
+    answers, bench_history, config, examples, progress, readme_benchmarks, submissions, vault,
+};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Client(AocClientError),
+    Answers(answers::Error),
+    BenchHistory(bench_history::Error),
+    Config(config::Error),
+    Examples(examples::Error),
+    Inputs(inputs::Error),
+    Progress(progress::Error),
+    ReadmeBenchmarks(readme_benchmarks::Error),
+    Submissions(submissions::Error),
+    Vault(vault::Error),
+    All(all::Error),
+    /// The puzzle input of a day has a problem that keeps the solution from running.
+    Input(PuzzleId, Problem),
+    /// The command could not finish or found problems, e.g. incorrect answers.
+    Failed(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Client(e) => write!(f, "{e}"),
+            Error::Answers(e) => write!(f, "{e}"),
+            Error::BenchHistory(e) => write!(f, "{e}"),
+            Error::Config(e) => write!(f, "{e}"),
+            Error::Examples(e) => write!(f, "{e}"),
+            Error::Inputs(e) => write!(f, "{e}"),
+            Error::Progress(e) => write!(f, "{e}"),
+            Error::ReadmeBenchmarks(e) => write!(f, "{e}"),
+            Error::Submissions(e) => write!(f, "{e}"),
+            Error::Vault(e) => write!(f, "{e}"),
+            Error::All(e) => write!(f, "{e}"),
+            Error::Input(puzzle, problem) => {
+                write!(f, "{}.", inputs::describe(*puzzle, *problem))
+            }
+            Error::Failed(message) => f.write_str(message),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<AocClientError> for Error {
+    fn from(e: AocClientError) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl From<answers::Error> for Error {
+    fn from(e: answers::Error) -> Self {
+        Error::Answers(e)
+    }
+}
+
+impl From<bench_history::Error> for Error {
+    fn from(e: bench_history::Error) -> Self {
+        Error::BenchHistory(e)
+    }
+}
+
+impl From<config::Error> for Error {
+    fn from(e: config::Error) -> Self {
+        Error::Config(e)
+    }
+}
+
+impl From<examples::Error> for Error {
+    fn from(e: examples::Error) -> Self {
+        Error::Examples(e)
+    }
+}
+
+impl From<inputs::Error> for Error {
+    fn from(e: inputs::Error) -> Self {
+        Error::Inputs(e)
+    }
+}
+
+impl From<progress::Error> for Error {
+    fn from(e: progress::Error) -> Self {
+        Error::Progress(e)
+    }
+}
+
+impl From<readme_benchmarks::Error> for Error {
+    fn from(e: readme_benchmarks::Error) -> Self {
+        Error::ReadmeBenchmarks(e)
+    }
+}
+
+impl From<submissions::Error> for Error {
+    fn from(e: submissions::Error) -> Self {
+        Error::Submissions(e)
+    }
+}
+
+impl From<vault::Error> for Error {
+    fn from(e: vault::Error) -> Self {
+        Error::Vault(e)
+    }
+}
+
+impl From<all::Error> for Error {
+    fn from(e: all::Error) -> Self {
+        Error::All(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io;
+
+    use super::Error;
+    use crate::template::inputs::Problem;
+    use crate::template::{answers, vault};
+    use crate::{day, year, PuzzleId};
+
+    #[test]
+    fn wraps_errors() {
+        let error: Error = io::Error::new(io::ErrorKind::NotFound, "gone").into();
+        assert!(matches!(error, Error::IO(_)));
+
+        let error: Error = answers::Error::Parser("`part3` is not a part.".into()).into();
+        assert_eq!(
+            error.to_string(),
+            "could not parse recorded answers: `part3` is not a part."
+        );
+
+        let error: Error = vault::Error::InvalidKey.into();
+        assert_eq!(
+            error.to_string(),
+            "the vault key should be 64 hexadecimal characters."
+        );
+    }
+
+    #[test]
+    fn describes_input_problems() {
+        let error = Error::Input(PuzzleId::new(year!(2023), day!(5)), Problem::Empty);
+        assert_eq!(
+            error.to_string(),
+            "data/2023/inputs/05.txt is empty, run `cargo download 5` to download it."
+        );
+    }
+}
@@ -0,0 +1,327 @@
+/// Module that runs a solution against every named example of a puzzle.
+/// Examples live in `data/<year>/examples/<day>/<name>.txt`, each with an optional sidecar
+/// `<name>.toml` that holds its expected answers in the same format as the recorded answers.
+use std::{fs, io, path::Path};
+
+use crate::template::answers::{self, Answers, Verdict};
+use crate::template::report::{self, Format};
+use crate::template::runner::{format_part, PartResult, RunOptions, Solution};
+use crate::template::{get_data_dir, Error as TemplateError, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse examples: {e}"),
+            Error::IO(e) => write!(f, "could not read examples: {e}"),
+        }
+    }
+}
+
+impl From<answers::Error> for Error {
+    fn from(e: answers::Error) -> Self {
+        match e {
+            answers::Error::Parser(s) => Error::Parser(s),
+            answers::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// A part whose answer does not match the expected answer of an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub example: String,
+    pub part: u8,
+    pub expected: String,
+    pub answer: Option<String>,
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "example `{}` {}: expected {}, got {}",
+            self.example,
+            format_part(self.part),
+            self.expected,
+            self.answer.as_deref().unwrap_or("nothing")
+        )
+    }
+}
+
+/// Returns the directory of the named examples of a puzzle, e.g. `data/2023/examples/05`.
+#[must_use]
+pub fn get_examples_dir(puzzle: PuzzleId) -> String {
+    format!("{}/{}/examples/{}", get_data_dir(), puzzle.year, puzzle.day)
+}
+
+/// Reads the named examples of a puzzle, ordered by name. A missing directory means there are no examples.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Example>, Error> {
+    let entries = match fs::read_dir(get_examples_dir(puzzle)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut examples = vec![];
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            examples.push(read_example(&path)?);
+        }
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+fn read_example(path: &Path) -> Result<Example, Error> {
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let answers = match fs::read_to_string(path.with_extension("toml")) {
+        Ok(s) => Answers::parse(&s)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(Example {
+        name,
+        input: fs::read_to_string(path)?,
+        answers,
+    })
+}
+
+/// Compares results against the expected answers of an example and sets their verdicts.
+fn check(example: &Example, results: &mut [PartResult]) -> Vec<Failure> {
+    let mut failures = vec![];
+
+    for result in results.iter_mut().filter(|r| !r.is_parse()) {
+        result.verdict = example
+            .answers
+            .verify(result.part, result.answer.as_deref());
+
+        if let Verdict::Incorrect { expected } = &result.verdict {
+            failures.push(Failure {
+                example: example.name.clone(),
+                part: result.part,
+                expected: expected.clone(),
+                answer: result.answer.clone(),
+            });
+        }
+    }
+
+    failures
+}
+
+fn run_examples(
+    solution: &Solution,
+    examples: &[Example],
+    options: &RunOptions,
+) -> (Vec<PartResult>, Vec<Failure>) {
+    // submitting is only supported for puzzle inputs and recorded answers do not apply to examples.
+    let options = &RunOptions {
+        submit: None,
+        skip_verify: true,
+        ..*options
+    };
+
+    let is_text = options.format == Format::Text;
+
+    let mut all_results = vec![];
+    let mut all_failures = vec![];
+
+    for (i, example) in examples.iter().enumerate() {
+        if is_text {
+            if i > 0 {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Example {}{ANSI_RESET}", example.name);
+            println!("------");
+        }
+
+        let mut results = solution.run(&example.input, options);
+        let failures = check(example, &mut results);
+
+        if is_text {
+            if failures.is_empty() {
+                println!("✔ Passed");
+            }
+
+            for failure in &failures {
+                println!("✘ {failure}");
+            }
+        }
+
+        all_results.extend(results);
+        all_failures.extend(failures);
+    }
+
+    (all_results, all_failures)
+}
+
+/// Run a solution against all of its named examples, failing if any of them fails.
+pub fn run(solution: &Solution, options: &RunOptions) -> Result<(), TemplateError> {
+    let examples = read(solution.puzzle)?;
+
+    if examples.is_empty() {
+        return Err(TemplateError::Failed(format!(
+            "no examples found in \"{}\".",
+            get_examples_dir(solution.puzzle)
+        )));
+    }
+
+    let (results, failures) = run_examples(solution, &examples, options);
+
+    report::print(options.format, &results);
+
+    if !failures.is_empty() {
+        return Err(TemplateError::Failed(format!(
+            "{} of {} example(s) failed.",
+            count_examples(&failures),
+            examples.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Asserts that a solution produces the expected answers for all of its named examples.
+/// Meant to be called from the tests of a solution.
+///
+/// # Panics
+///
+/// Panics if there are no examples, they can not be read or any example produces an incorrect
+/// answer.
+pub fn assert_examples(solution: &Solution) {
+    let examples = read(solution.puzzle).expect("could not read examples");
+
+    assert!(
+        !examples.is_empty(),
+        "no examples for {} in \"{}\".",
+        solution.puzzle,
+        get_examples_dir(solution.puzzle)
+    );
+
+    // only text output is printed while running.
+    let options = RunOptions {
+        format: Format::Json,
+        ..RunOptions::default()
+    };
+
+    let (_, failures) = run_examples(solution, &examples, &options);
+
+    let messages: Vec<String> = failures.iter().map(ToString::to_string).collect();
+    assert!(messages.is_empty(), "{}", messages.join("\n"));
+}
+
+fn count_examples(failures: &[Failure]) -> usize {
+    let mut names: Vec<&str> = failures.iter().map(|f| f.example.as_str()).collect();
+    names.dedup();
+    names.len()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{assert_examples, check, count_examples, Example, Failure};
+    use crate::template::answers::{Answers, Verdict};
+    use crate::template::runner::{DurationStats, PartResult, Solution, PARSE_PART};
+    use crate::{day, year, PuzzleId};
+
+    fn part_result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            part,
+            answer: answer.map(Into::into),
+            verdict: Verdict::Unknown,
+            samples: 1,
+            stats: DurationStats::default(),
+            failure: None,
+        }
+    }
+
+    fn example(name: &str, answers: &str) -> Example {
+        Example {
+            name: name.into(),
+            input: String::new(),
+            answers: Answers::parse(answers).unwrap(),
+        }
+    }
+
+    #[test]
+    fn checks_expected_answers() {
+        let mut results = vec![
+            part_result(PARSE_PART, None),
+            part_result(1, Some("142")),
+            part_result(2, Some("1")),
+        ];
+
+        let failures = check(&example("small", "part1 = 142\npart2 = 2\n"), &mut results);
+
+        assert_eq!(results[0].verdict, Verdict::Unknown);
+        assert_eq!(results[1].verdict, Verdict::Correct);
+        assert_eq!(
+            failures,
+            vec![Failure {
+                example: "small".into(),
+                part: 2,
+                expected: "2".into(),
+                answer: Some("1".into()),
+            }]
+        );
+        assert_eq!(
+            failures[0].to_string(),
+            "example `small` part 2: expected 2, got 1"
+        );
+    }
+
+    #[test]
+    fn skips_parts_without_expected_answers() {
+        let mut results = vec![part_result(1, Some("142")), part_result(2, None)];
+        let failures = check(&example("part_1_only", "part1 = 142\n"), &mut results);
+        assert!(failures.is_empty());
+        assert_eq!(results[1].verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn counts_failed_examples() {
+        let mut results = vec![part_result(1, None), part_result(2, None)];
+        let mut failures = check(&example("a", "part1 = 1\npart2 = 2\n"), &mut results);
+        failures.extend(check(&example("b", "part1 = 1\n"), &mut results));
+        assert_eq!(failures.len(), 3);
+        assert_eq!(count_examples(&failures), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "no examples for 2015-25")]
+    fn asserts_that_examples_exist() {
+        assert_examples(&Solution {
+            puzzle: PuzzleId::new(year!(2015), day!(25)),
+            run_parts: |_, _| vec![],
+        });
+    }
+}
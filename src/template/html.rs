@@ -0,0 +1,416 @@
+//! Module with a minimal HTML parser, just enough for the pages of adventofcode.com,
+//! and a converter of puzzle descriptions to Markdown.
+
+/// A node of a parsed HTML document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+impl Element {
+    fn new(name: &str, attributes: Vec<(String, String)>) -> Self {
+        Element {
+            name: name.into(),
+            attributes,
+            children: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[must_use]
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attr("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+    }
+
+    /// The text content of the element and all its descendants.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Element(element) => element.text(),
+                Node::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+
+    /// All descendant elements with a tag name, in document order.
+    #[must_use]
+    pub fn find_all(&self, name: &str) -> Vec<&Element> {
+        let mut found = vec![];
+
+        for child in &self.children {
+            if let Node::Element(element) = child {
+                if element.name == name {
+                    found.push(element);
+                }
+                found.extend(element.find_all(name));
+            }
+        }
+
+        found
+    }
+
+    /// The child elements, skipping text nodes.
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+}
+
+/// Decodes the character references that appear on adventofcode.com.
+#[must_use]
+pub fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+
+        let replacement = entity.and_then(|(name, _)| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let code = if let Some(hex) = name.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else {
+                    name.strip_prefix('#').and_then(|dec| dec.parse().ok())
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+
+        match (replacement, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn parse_attributes(s: &str) -> Vec<(String, String)> {
+    let mut attributes = vec![];
+    let mut rest = s.trim();
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+
+            let (value, remaining) = match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = after_eq[1..].find(quote).map_or(after_eq.len(), |i| i + 1);
+                    (&after_eq[1..end], after_eq.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..end], &after_eq[end..])
+                }
+            };
+
+            rest = remaining.trim_start();
+            decode_entities(value)
+        } else {
+            String::new()
+        };
+
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+    }
+
+    attributes
+}
+
+/// Closes the innermost open element with a tag name, along with all elements opened after it.
+fn close(stack: &mut Vec<Element>, name: &str) {
+    let Some(pos) = stack.iter().rposition(|e| e.name == name) else {
+        return;
+    };
+
+    // the root element is never closed.
+    while stack.len() > pos.max(1) {
+        let element = stack.pop().unwrap();
+        stack
+            .last_mut()
+            .unwrap()
+            .children
+            .push(Node::Element(element));
+    }
+}
+
+/// Parses an HTML document into a root element without a name.
+/// Unclosed elements are closed at the end of the document, stray closing tags are ignored.
+#[must_use]
+pub fn parse(html: &str) -> Element {
+    let mut stack = vec![Element::default()];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            let text = decode_entities(rest);
+            stack.last_mut().unwrap().children.push(Node::Text(text));
+            break;
+        };
+
+        if start > 0 {
+            let text = decode_entities(&rest[..start]);
+            stack.last_mut().unwrap().children.push(Node::Text(text));
+        }
+
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            close(&mut stack, &name.trim().to_lowercase());
+            continue;
+        }
+
+        let is_self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        let element = Element::new(&name, parse_attributes(&tag[name_end..]));
+
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let closing = format!("</{name}");
+            rest = rest
+                .to_ascii_lowercase()
+                .find(&closing)
+                .map_or("", |end| &rest[end..]);
+            stack
+                .last_mut()
+                .unwrap()
+                .children
+                .push(Node::Element(element));
+        } else if is_self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+            stack
+                .last_mut()
+                .unwrap()
+                .children
+                .push(Node::Element(element));
+        } else {
+            stack.push(element);
+        }
+    }
+
+    while stack.len() > 1 {
+        let element = stack.pop().unwrap();
+        stack
+            .last_mut()
+            .unwrap()
+            .children
+            .push(Node::Element(element));
+    }
+
+    stack.pop().unwrap()
+}
+
+fn render_inline(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element(element) => match element.name.as_str() {
+                "code" => {
+                    let code = format!("`{}`", element.text());
+                    // answers are usually emphasized code, e.g. `<code><em>142</em></code>`.
+                    if element.find_all("em").is_empty() {
+                        code
+                    } else {
+                        format!("*{code}*")
+                    }
+                }
+                "em" | "i" => format!("*{}*", render_inline(&element.children)),
+                "strong" | "b" => format!("**{}**", render_inline(&element.children)),
+                "a" => format!(
+                    "[{}]({})",
+                    render_inline(&element.children),
+                    element.attr("href").unwrap_or_default()
+                ),
+                "br" => "\n".into(),
+                _ => render_inline(&element.children),
+            },
+        })
+        .collect()
+}
+
+fn render_block(element: &Element, out: &mut String) {
+    match element.name.as_str() {
+        "h1" | "h2" | "h3" => {
+            let level = if element.name == "h3" { "###" } else { "##" };
+            out.push_str(&format!("{level} {}\n\n", render_inline(&element.children)));
+        }
+        "pre" => {
+            let text = element.text();
+            out.push_str("```\n");
+            out.push_str(&text);
+            if !text.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str("```\n\n");
+        }
+        "ul" | "ol" => {
+            for item in element.elements().filter(|e| e.name == "li") {
+                out.push_str(&format!("- {}\n", render_inline(&item.children).trim()));
+            }
+            out.push('\n');
+        }
+        "article" | "main" | "div" | "section" => {
+            for child in &element.children {
+                match child {
+                    Node::Element(element) => render_block(element, out),
+                    Node::Text(text) if !text.trim().is_empty() => {
+                        out.push_str(&format!("{}\n\n", text.trim()));
+                    }
+                    Node::Text(_) => {}
+                }
+            }
+        }
+        _ => {
+            out.push_str(&format!("{}\n\n", render_inline(&element.children)));
+        }
+    }
+}
+
+/// Converts the `<article>` elements of a puzzle page to Markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let document = parse(html);
+    let mut out = String::new();
+
+    for article in document.find_all("article") {
+        render_block(article, &mut out);
+    }
+
+    format!("{}\n", out.trim_end())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, parse, to_markdown, Node};
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27;"),
+            "a <b> & 'c'"
+        );
+        assert_eq!(decode_entities("&unknown; & done"), "&unknown; & done");
+    }
+
+    #[test]
+    fn parses_nested_elements() {
+        let document =
+            parse(r#"<!DOCTYPE html><p class="a b">x <em>y</em><br>z</p><!-- comment --><p>w"#);
+        let paragraphs = document.find_all("p");
+
+        assert_eq!(paragraphs.len(), 2);
+        assert!(paragraphs[0].has_class("b"));
+        assert_eq!(paragraphs[0].text(), "x yz");
+        assert_eq!(paragraphs[0].find_all("br").len(), 1);
+        assert_eq!(paragraphs[1].children, vec![Node::Text("w".into())]);
+    }
+
+    #[test]
+    fn skips_scripts() {
+        let document = parse("<script>if (a < b) { x(); }</script><p>text</p>");
+        assert_eq!(document.text(), "text");
+    }
+
+    #[test]
+    fn converts_articles_to_markdown() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>Something is <em>wrong</em> with <a href="/2023/support">global snow production</a>.</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul>
+<li>In <code>1abc2</code>, the value is <code>12</code>.</li>
+</ul>
+<p>The sum is <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54388</code>.</p>
+</main></body></html>"#;
+
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Something is *wrong* with [global snow production](/2023/support).",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "- In `1abc2`, the value is `12`.",
+            "",
+            "The sum is *`142`*.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(to_markdown(html), expected);
+    }
+}
@@ -0,0 +1,280 @@
+/// Module that checks puzzle inputs for common problems and records the hashes of downloaded inputs.
+///
+/// The SHA-256 hash of every input that `cargo download` saves is recorded in
+/// `data/<year>/checksums.toml`, e.g.:
+///
+/// ```toml
+/// 05 = "1c7a…"
+/// ```
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use std::{fs, io};
+
+use sha2::{Digest, Sha256};
+
+use crate::template::{get_data_dir, get_data_path};
+use crate::{Day, PuzzleId, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse checksums: {e}"),
+            Error::IO(e) => write!(f, "could not read or write checksums: {e}"),
+        }
+    }
+}
+
+/// A problem of an input file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The file is empty, e.g. the placeholder that `scaffold` creates.
+    Empty,
+    /// The file contains an HTML page or an error message of the server instead of an input.
+    ErrorPage,
+    /// The lines end with `\r\n`, e.g. after a checkout with `core.autocrlf`.
+    CrLf,
+    MissingTrailingNewline,
+    TrailingBlankLines,
+    /// The input does not match the hash recorded when it was downloaded.
+    Modified,
+}
+
+impl Problem {
+    /// Whether no solution can be expected to run against an input with this problem.
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Problem::Empty | Problem::ErrorPage)
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Problem::Empty => "is empty",
+            Problem::ErrorPage => "contains an error page instead of the puzzle input",
+            Problem::CrLf => "has Windows line endings (`\\r\\n`)",
+            Problem::MissingTrailingNewline => "does not end with a newline",
+            Problem::TrailingBlankLines => "ends with blank lines",
+            Problem::Modified => "differs from the downloaded input",
+        })
+    }
+}
+
+/// Messages the server sends instead of an input, e.g. when the session cookie expired.
+const ERROR_MESSAGES: [&str; 3] = [
+    "Please log in to get your puzzle input",
+    "Please don't repeatedly request this endpoint before it unlocks",
+    "404 Not Found",
+];
+
+/// Checks the contents of an input. Puzzle inputs end with exactly one newline.
+#[must_use]
+pub fn check(input: &str) -> Vec<Problem> {
+    if input.trim().is_empty() {
+        return vec![Problem::Empty];
+    }
+
+    let start = input.trim_start().to_lowercase();
+    if start.starts_with("<!doctype html")
+        || start.starts_with("<html")
+        || ERROR_MESSAGES.iter().any(|message| input.contains(message))
+    {
+        return vec![Problem::ErrorPage];
+    }
+
+    let mut problems = vec![];
+
+    if input.contains("\r\n") {
+        problems.push(Problem::CrLf);
+    }
+
+    let content = input.trim_end_matches(['\r', '\n']);
+    match input[content.len()..].matches('\n').count() {
+        0 => problems.push(Problem::MissingTrailingNewline),
+        1 => {}
+        _ => problems.push(Problem::TrailingBlankLines),
+    }
+
+    problems
+}
+
+/// Returns the hex-encoded SHA-256 hash of an input.
+#[must_use]
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// The hashes of the downloaded inputs of a year.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Checksums(BTreeMap<Day, String>);
+
+#[must_use]
+pub fn get_checksums_path(year: Year) -> String {
+    format!("{}/{year}/checksums.toml", get_data_dir())
+}
+
+impl Checksums {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let table: toml::Table = s.parse().map_err(|e| Error::Parser(format!("{e}")))?;
+
+        table
+            .iter()
+            .map(|(key, value)| {
+                let day = key
+                    .parse()
+                    .ok()
+                    .and_then(Day::new)
+                    .ok_or_else(|| Error::Parser(format!("`{key}` is not a day.")))?;
+
+                let hash = value
+                    .as_str()
+                    .ok_or_else(|| Error::Parser(format!("`{key}` should be a string.")))?;
+
+                Ok((day, hash.to_string()))
+            })
+            .collect::<Result<_, _>>()
+            .map(Checksums)
+    }
+
+    /// Reads the checksums of a year. A missing file means no input was downloaded yet.
+    pub fn read(year: Year) -> Result<Self, Error> {
+        match fs::read_to_string(get_checksums_path(year)) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Checksums::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        self.0
+            .iter()
+            .map(|(day, hash)| format!("{day} = \"{hash}\"\n"))
+            .collect()
+    }
+
+    pub fn write(&self, year: Year) -> Result<(), Error> {
+        fs::write(get_checksums_path(year), self.to_toml())?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.0.get(&day).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: Day, hash: String) {
+        self.0.insert(day, hash);
+    }
+}
+
+/// Checks the puzzle input of a day, including whether it matches the hash recorded when it was
+/// downloaded.
+#[must_use]
+pub fn verify(puzzle: PuzzleId, input: &str) -> Vec<Problem> {
+    let mut problems = check(input);
+
+    let is_modified = Checksums::read(puzzle.year)
+        .ok()
+        .and_then(|checksums| checksums.get(puzzle.day).map(|h| h != hash(input)))
+        .unwrap_or(false);
+
+    if is_modified && !problems.iter().any(Problem::is_fatal) {
+        problems.push(Problem::Modified);
+    }
+
+    problems
+}
+
+/// Describes a problem of the puzzle input of a day, with a hint how to fix it.
+#[must_use]
+pub fn describe(puzzle: PuzzleId, problem: Problem) -> String {
+    let path = get_data_path("inputs", puzzle, "txt");
+
+    let hint = match problem {
+        Problem::Empty | Problem::ErrorPage | Problem::Modified => format!(
+            ", run `cargo download {}` to download it",
+            puzzle.day.into_inner()
+        ),
+        _ => String::new(),
+    };
+
+    format!("{path} {problem}{hint}")
+}
+
+/// Prints a warning for every problem of the puzzle input of a day.
+pub fn warn(puzzle: PuzzleId, problems: &[Problem]) {
+    for problem in problems {
+        eprintln!("Warning: {}.", describe(puzzle, *problem));
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, hash, Checksums, Problem};
+    use crate::day;
+
+    #[test]
+    fn accepts_inputs() {
+        assert_eq!(check("1 2\n3 4\n"), vec![]);
+    }
+
+    #[test]
+    fn detects_problems() {
+        assert_eq!(check(""), vec![Problem::Empty]);
+        assert_eq!(check("\n"), vec![Problem::Empty]);
+        assert_eq!(
+            check("<!DOCTYPE html>\n<html><body>500</body></html>\n"),
+            vec![Problem::ErrorPage]
+        );
+        assert_eq!(
+            check("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            vec![Problem::ErrorPage]
+        );
+        assert_eq!(check("1 2\r\n3 4\r\n"), vec![Problem::CrLf]);
+        assert_eq!(check("1 2\n3 4"), vec![Problem::MissingTrailingNewline]);
+        assert_eq!(check("1 2\n3 4\n\n"), vec![Problem::TrailingBlankLines]);
+        assert_eq!(
+            check("1 2\r\n3 4"),
+            vec![Problem::CrLf, Problem::MissingTrailingNewline]
+        );
+    }
+
+    #[test]
+    fn hashes_inputs() {
+        assert_eq!(
+            hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn parses_checksums() {
+        let mut checksums = Checksums::parse("05 = \"abc\"\n").unwrap();
+        assert_eq!(checksums.get(day!(5)), Some("abc"));
+        assert_eq!(checksums.get(day!(1)), None);
+
+        checksums.insert(day!(1), "def".into());
+        assert_eq!(checksums.to_toml(), "01 = \"def\"\n05 = \"abc\"\n");
+
+        assert!(Checksums::parse("26 = \"abc\"\n").is_err());
+        assert!(Checksums::parse("05 = 1\n").is_err());
+    }
+}
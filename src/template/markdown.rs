@@ -0,0 +1,205 @@
+//! Module that renders puzzle descriptions for the terminal. It supports the subset of Markdown
+//! that [`html::to_markdown`](crate::template::html::to_markdown) produces.
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// Selects one part of a puzzle description, or [`None`] if the description does not have it.
+#[must_use]
+pub fn select_part(description: &str, part: u8) -> Option<&str> {
+    let part_two = description
+        .lines()
+        .position(|line| line.trim_end() == PART_TWO_HEADING)
+        .map(|line| {
+            description
+                .split_inclusive('\n')
+                .take(line)
+                .map(str::len)
+                .sum::<usize>()
+        });
+
+    match (part, part_two) {
+        (1, Some(start)) => Some(&description[..start]),
+        (1, None) => Some(description),
+        (2, Some(start)) => Some(&description[start..]),
+        _ => None,
+    }
+}
+
+/// The styles that are active while rendering inline text.
+#[derive(Default)]
+struct Style {
+    bold: bool,
+    italic: bool,
+}
+
+impl Style {
+    fn apply(&self, out: &mut String) {
+        out.push_str(ANSI_RESET);
+        if self.bold {
+            out.push_str(ANSI_BOLD);
+        }
+        if self.italic {
+            out.push_str(ANSI_ITALIC);
+        }
+    }
+}
+
+/// Renders emphasis, strong emphasis, code and links of a line.
+fn render_inline(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::with_capacity(line.len());
+    let mut style = Style::default();
+    let mut i = 0;
+
+    let is_space = |i: usize| chars.get(i).is_none_or(|c| c.is_whitespace());
+
+    while i < chars.len() {
+        match chars[i] {
+            '`' => {
+                let end = chars[i + 1..].iter().position(|&c| c == '`');
+
+                if let Some(end) = end {
+                    out.extend(&chars[i + 1..i + 1 + end]);
+                    i += end + 2;
+                    continue;
+                }
+
+                out.push('`');
+            }
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let is_open = !style.bold && !is_space(i + 2);
+                let is_close = style.bold && i > 0 && !is_space(i - 1);
+
+                if is_open || is_close {
+                    style.bold = !style.bold;
+                    style.apply(&mut out);
+                } else {
+                    out.push_str("**");
+                }
+
+                i += 2;
+                continue;
+            }
+            '*' => {
+                // a lone `*` between spaces is a multiplication, not emphasis.
+                let is_open = !style.italic && !is_space(i + 1);
+                let is_close = style.italic && i > 0 && !is_space(i - 1);
+
+                if is_open || is_close {
+                    style.italic = !style.italic;
+                    style.apply(&mut out);
+                } else {
+                    out.push('*');
+                }
+            }
+            '[' => {
+                let text_end = chars[i..].iter().position(|&c| c == ']').map(|e| i + e);
+                let link_end = text_end
+                    .filter(|&e| chars.get(e + 1) == Some(&'('))
+                    .and_then(|e| chars[e..].iter().position(|&c| c == ')').map(|l| e + l));
+
+                if let (Some(text_end), Some(link_end)) = (text_end, link_end) {
+                    out.push_str(&render_inline(
+                        &chars[i + 1..text_end].iter().collect::<String>(),
+                    ));
+                    // the link text resets its own styles.
+                    if style.bold || style.italic {
+                        style.apply(&mut out);
+                    }
+                    i = link_end + 1;
+                    continue;
+                }
+
+                out.push('[');
+            }
+            c => out.push(c),
+        }
+
+        i += 1;
+    }
+
+    if style.bold || style.italic {
+        out.push_str(ANSI_RESET);
+    }
+
+    out
+}
+
+/// Renders a puzzle description with ANSI styles: headings are bold, code blocks are indented and
+/// emphasis is italic.
+#[must_use]
+pub fn to_ansi(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push_str(&format!("    {line}\n"));
+        } else if let Some(heading) = line
+            .strip_prefix("## ")
+            .or_else(|| line.strip_prefix("### "))
+        {
+            out.push_str(&format!("{ANSI_BOLD}{heading}{ANSI_RESET}\n"));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            out.push_str(&format!("  • {}\n", render_inline(item)));
+        } else {
+            out.push_str(&render_inline(line));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render_inline, select_part, to_ansi};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const DESCRIPTION: &str =
+        "## --- Day 1: Trebuchet?! ---\n\nPart one.\n\n## --- Part Two ---\n\nPart two.\n";
+
+    #[test]
+    fn selects_parts() {
+        assert_eq!(
+            select_part(DESCRIPTION, 1),
+            Some("## --- Day 1: Trebuchet?! ---\n\nPart one.\n\n")
+        );
+        assert_eq!(
+            select_part(DESCRIPTION, 2),
+            Some("## --- Part Two ---\n\nPart two.\n")
+        );
+        assert_eq!(select_part("## --- Day 1 ---\n", 2), None);
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            render_inline("a *b* and **c *d* e**"),
+            format!("a {ANSI_RESET}{ANSI_ITALIC}b{ANSI_RESET} and {ANSI_RESET}{ANSI_BOLD}c {ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}d{ANSI_RESET}{ANSI_BOLD} e{ANSI_RESET}")
+        );
+        assert_eq!(render_inline("`4` * `8`"), "4 * 8");
+        assert_eq!(
+            render_inline("produces *`142`*."),
+            format!("produces {ANSI_RESET}{ANSI_ITALIC}142{ANSI_RESET}.")
+        );
+        assert_eq!(render_inline("the [snow](/2023/support)"), "the snow");
+    }
+
+    #[test]
+    fn renders_blocks() {
+        let markdown = "## --- Day 1 ---\n\n```\n1abc2\n```\n\n- In `1abc2`.\n";
+        assert_eq!(
+            to_ansi(markdown),
+            format!("{ANSI_BOLD}--- Day 1 ---{ANSI_RESET}\n\n    1abc2\n\n  • In 1abc2.\n")
+        );
+    }
+}
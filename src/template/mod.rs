@@ -1,36 +1,119 @@
-use crate::Day;
+use crate::PuzzleId;
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod answer;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench_history;
 pub mod commands;
+pub mod config;
+mod error;
+pub mod examples;
+pub mod html;
+pub mod inputs;
+pub mod markdown;
+pub mod progress;
 pub mod readme_benchmarks;
+pub mod report;
 pub mod runner;
+pub mod submissions;
+pub mod vault;
+
+pub use error::Error;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Returns the directory of inputs, examples and other data, `data` unless it is set by
+/// `AOC_DATA_DIR` or the `data_dir` of the project config, see [`config`]. The binaries report an
+/// invalid config before they use any paths.
+#[must_use]
+pub fn get_data_dir() -> String {
+    env::var("AOC_DATA_DIR")
+        .ok()
+        .or_else(|| config::get().ok()?.data_dir.clone())
+        .unwrap_or_else(|| "data".into())
+}
+
+/// Returns the path of a data file for a puzzle, e.g. `data/2023/inputs/05.txt`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId, extension: &str) -> String {
+    format!(
+        "{}/{}/{folder}/{}.{extension}",
+        get_data_dir(),
+        puzzle.year,
+        puzzle.day
+    )
+}
+
+/// Returns the path of the solution binary for a puzzle, e.g. `src/bin/2023/05.rs`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("src/bin/{}/{}.rs", puzzle.year, puzzle.day)
+}
+
+/// Helper function that reads a text file to a string. Locked puzzle inputs are decrypted, see [`vault`].
+#[must_use]
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    if folder == "inputs" {
+        return vault::read_input(puzzle).expect("could not open input file");
+    }
+
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(get_data_path(folder, puzzle, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `PUZZLE` and `SOLUTION` and sets up the input and runner for each part.
+///
+/// `SOLUTION` is picked up by the registry that `build.rs` generates for the main binary.
+///
+/// By default, `part_one` and `part_two` take the input as `&str`. If a `parse` function is passed,
+/// e.g. `solution!(2023, 5, parse)`, the input is parsed once and both parts take a reference
+/// to the parsed input instead. Parsing is then timed separately from the parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    (@with $year:expr, $day:expr, $run_parts:expr) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId =
+            advent_of_code::PuzzleId::new(advent_of_code::year!($year), advent_of_code::day!($day));
 
+        /// The registry entry for this puzzle.
+        pub const SOLUTION: advent_of_code::template::runner::Solution =
+            advent_of_code::template::runner::Solution {
+                puzzle: PUZZLE,
+                run_parts: $run_parts,
+            };
+
+        // only used when the solution is built as its own binary.
+        #[allow(dead_code)]
         fn main() {
-            use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            if let Err(e) = advent_of_code::template::runner::run_from_args(&SOLUTION) {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
         }
     };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@with $year, $day, |input, options| {
+            use advent_of_code::template::runner::run_part;
+            vec![
+                run_part(part_one, input, PUZZLE, 1, options),
+                run_part(part_two, input, PUZZLE, 2, options),
+            ]
+        });
+    };
+    ($year:expr, $day:expr, $parse:expr) => {
+        advent_of_code::solution!(@with $year, $day, |input, options| {
+            use advent_of_code::template::runner::{run_parse, run_part};
+            let (parsed, parse_result) = run_parse($parse, input, PUZZLE, options);
+            vec![
+                parse_result,
+                run_part(part_one, &parsed, PUZZLE, 1, options),
+                run_part(part_two, &parsed, PUZZLE, 2, options),
+            ]
+        });
+    };
 }
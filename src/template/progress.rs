@@ -0,0 +1,254 @@
+/// Module that collects the stars of a year: the parts solved locally according to the recorded
+/// answers, the stars on the calendar of adventofcode.com and the members of a private leaderboard.
+use std::collections::BTreeMap;
+
+use crate::template::answers::{self, Answers};
+use crate::template::html;
+use crate::{all_days, Day, PuzzleId, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse leaderboard: {e}"),
+        }
+    }
+}
+
+/// The number of stars of every day, days without stars are missing.
+pub type Stars = BTreeMap<Day, u8>;
+
+/// Counts the parts with a recorded answer, see [`answers`](crate::template::answers).
+pub fn local_stars(year: Year) -> Result<Stars, answers::Error> {
+    let mut stars = Stars::new();
+
+    for day in all_days() {
+        let answers = Answers::read(PuzzleId::new(year, day))?;
+        let count = [&answers.part_1, &answers.part_2]
+            .iter()
+            .filter(|answer| answer.is_some())
+            .count();
+
+        if count > 0 {
+            stars.insert(day, count as u8);
+        }
+    }
+
+    Ok(stars)
+}
+
+/// Reads the stars from the calendar page of a year, where solved days are links with the class
+/// `calendar-complete` for one star or `calendar-verycomplete` for two stars.
+#[must_use]
+pub fn parse_calendar(page: &str) -> Stars {
+    let document = html::parse(page);
+    let mut stars = Stars::new();
+
+    for link in document.find_all("a") {
+        let count = if link.has_class("calendar-verycomplete") {
+            2
+        } else if link.has_class("calendar-complete") {
+            1
+        } else {
+            continue;
+        };
+
+        let day = link
+            .attr("href")
+            .and_then(|href| href.rsplit('/').next())
+            .and_then(|day| day.parse().ok())
+            .and_then(Day::new);
+
+        if let Some(day) = day {
+            stars.insert(day, count);
+        }
+    }
+
+    stars
+}
+
+/// Renders the stars of a day, e.g. `★·` for a day with only part one solved.
+#[must_use]
+pub fn format_stars(count: u8) -> String {
+    match count {
+        0 => "··".into(),
+        1 => "★·".into(),
+        _ => "★★".into(),
+    }
+}
+
+/// A member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// When the member got their last star, in seconds since the unix epoch.
+    pub last_star: u64,
+    pub days: Stars,
+}
+
+/// Parses the JSON of a private leaderboard and ranks its members by score, breaking ties by stars
+/// and by who got their last star first.
+pub fn parse_leaderboard(json: &str) -> Result<Vec<Member>, Error> {
+    let root: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| Error::Parser(format!("{e}")))?;
+
+    let members = root
+        .get("members")
+        .and_then(serde_json::Value::as_object)
+        .ok_or_else(|| Error::Parser("missing `members`.".into()))?;
+
+    let mut ranking = members
+        .values()
+        .map(|member| {
+            let number = |key: &str| member.get(key).and_then(serde_json::Value::as_u64);
+
+            let id = number("id").ok_or_else(|| Error::Parser("member without `id`.".into()))?;
+
+            let days = member
+                .get("completion_day_level")
+                .and_then(serde_json::Value::as_object)
+                .into_iter()
+                .flatten()
+                .filter_map(|(day, parts)| {
+                    let day = Day::new(day.parse().ok()?)?;
+                    let count = parts.as_object()?.len().min(2) as u8;
+                    Some((day, count))
+                })
+                .collect();
+
+            Ok(Member {
+                id,
+                name: member
+                    .get("name")
+                    .and_then(serde_json::Value::as_str)
+                    .map_or_else(|| format!("(anonymous user #{id})"), ToString::to_string),
+                local_score: number("local_score").unwrap_or_default(),
+                stars: number("stars").unwrap_or_default(),
+                last_star: number("last_star_ts").unwrap_or_default(),
+                days,
+            })
+        })
+        .collect::<Result<Vec<Member>, Error>>()?;
+
+    ranking.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.last_star.cmp(&b.last_star))
+            .then(a.id.cmp(&b.id))
+    });
+
+    Ok(ranking)
+}
+
+/// Renders a ranking as a table with a column of stars for every day up to `last_day`.
+#[must_use]
+pub fn format_ranking(ranking: &[Member], last_day: u8) -> String {
+    let name_width = ranking
+        .iter()
+        .map(|member| member.name.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+
+    let days: Vec<Day> = all_days().take(last_day.into()).collect();
+
+    let header = format!(
+        "{:>3}  {:name_width$}  {:>5}  {:>5}  {}",
+        "#",
+        "Name",
+        "Score",
+        "Stars",
+        days.iter()
+            .map(|day| format!("{:<2}", day.into_inner()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    let mut lines = vec![header.trim_end().to_string()];
+
+    for (i, member) in ranking.iter().enumerate() {
+        let stars = days
+            .iter()
+            .map(|day| format_stars(member.days.get(day).copied().unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        lines.push(format!(
+            "{:>3}  {:name_width$}  {:>5}  {:>5}  {stars}",
+            i + 1,
+            member.name,
+            member.local_score,
+            member.stars,
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_ranking, parse_calendar, parse_leaderboard};
+    use crate::day;
+
+    #[test]
+    fn parses_leaderboards() {
+        let ranking = parse_leaderboard(include_str!("fixtures/leaderboard_2023.json")).unwrap();
+
+        let names: Vec<&str> = ranking.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Grace", "Ada", "(anonymous user #1003)", "Linus"]
+        );
+
+        assert_eq!(ranking[0].local_score, 23);
+        assert_eq!(ranking[0].stars, 5);
+        assert_eq!(ranking[0].days.get(&day!(2)), Some(&2));
+        assert_eq!(ranking[0].days.get(&day!(3)), Some(&1));
+        assert!(ranking[3].days.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboards() {
+        assert!(parse_leaderboard("{").is_err());
+        assert!(parse_leaderboard(r#"{"event":"2023"}"#).is_err());
+    }
+
+    #[test]
+    fn formats_rankings() {
+        let ranking = parse_leaderboard(include_str!("fixtures/leaderboard_2023.json")).unwrap();
+
+        let expected = [
+            "  #  Name                    Score  Stars  1  2  3",
+            "  1  Grace                      23      5  ★★ ★★ ★·",
+            "  2  Ada                        21      5  ★★ ★★ ★·",
+            "  3  (anonymous user #1003)      1      1  ★· ·· ··",
+            "  4  Linus                       0      0  ·· ·· ··",
+        ]
+        .join("\n");
+
+        assert_eq!(format_ranking(&ranking, 3), expected);
+    }
+
+    #[test]
+    fn parses_calendars() {
+        let page = r#"<pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">1</a>
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">2</a>
+<a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">3</a>
+</pre>"#;
+
+        let stars = parse_calendar(page);
+
+        assert_eq!(stars.get(&day!(1)), Some(&2));
+        assert_eq!(stars.get(&day!(2)), Some(&1));
+        assert_eq!(stars.get(&day!(3)), None);
+    }
+}
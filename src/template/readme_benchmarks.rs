@@ -2,7 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::get_path_for_bin;
+use crate::template::runner::Statistic;
+use crate::PuzzleId;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -18,9 +20,20 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse README: {e}"),
+            Error::IO(e) => write!(f, "could not read or write README: {e}"),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// Only present for solutions with a separate parse phase.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
@@ -31,11 +44,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -58,23 +66,55 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    statistic: Statistic,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    // the parse column is only shown if any solution has a separate parse phase.
+    let has_parse = timings.iter().any(|t| t.parse.is_some());
+
+    let suffix = match statistic {
+        Statistic::Mean => String::new(),
+        statistic => format!(" ({statistic})"),
+    };
+
+    let (columns, alignment) = if has_parse {
+        (
+            format!("| Day | Parse{suffix} | Part 1{suffix} | Part 2{suffix} |"),
+            "| :---: | :---: | :---: | :---:  |",
+        )
+    } else {
+        (
+            format!("| Day | Part 1{suffix} | Part 2{suffix} |"),
+            "| :---: | :---: | :---:  |",
+        )
+    };
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        columns,
+        alignment.into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
+        let parse = if has_parse {
+            format!(" `{}` |", timing.parse.unwrap_or_else(|| "-".into()))
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}](./{}) |{} `{}` | `{}` |",
+            timing.puzzle.day.into_inner(),
             path,
+            parse,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
@@ -87,17 +127,22 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    statistic: Statistic,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, statistic);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(timings: Vec<Timings>, total_millis: f64, statistic: Statistic) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, statistic)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -105,24 +150,28 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::runner::Statistic;
+    use crate::{day, year, PuzzleId};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
+                puzzle: PuzzleId::new(year!(2023), day!(4)),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
@@ -134,28 +183,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +212,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Mean).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -172,9 +221,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +232,23 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_median_benchmarks() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
+        assert!(s.contains("| Day | Part 1 (median) | Part 2 (median) |"));
+    }
+
+    #[test]
+    fn format_parse_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, Statistic::Mean).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
 }
@@ -0,0 +1,260 @@
+/// Machine-readable output of solution results, for consumption by other tools.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::runner::PartResult;
+
+/// The output format of `solve` and `all`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output, printed while the solutions run.
+    #[default]
+    Text,
+    /// A JSON array with one object per part.
+    Json,
+    /// A CSV table with a header and one row per part.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = FormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(FormatFromStrError),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`Format`].
+#[derive(Debug)]
+pub struct FormatFromStrError;
+
+impl Error for FormatFromStrError {}
+
+impl Display for FormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text`, `json` or `csv`")
+    }
+}
+
+const CSV_HEADER: &str =
+    "year,day,part,answer,status,verdict,samples,min_ns,mean_ns,median_ns,p95_ns,max_ns,std_dev_ns,outliers";
+
+/// A part in the JSON and CSV output. Durations are in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// `success`, `failure` if the part returned no answer, or `panic` or `timeout`.
+    pub status: String,
+    pub verdict: String,
+    pub samples: u128,
+    pub min_ns: u128,
+    pub mean_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+    pub max_ns: u128,
+    pub std_dev_ns: u128,
+    pub outliers: usize,
+}
+
+impl From<&PartResult> for Record {
+    fn from(result: &PartResult) -> Self {
+        Record {
+            year: result.puzzle.year.into_inner(),
+            day: result.puzzle.day.into_inner(),
+            part: result.part,
+            answer: result.answer.clone(),
+            status: status(result).into(),
+            verdict: result.verdict.as_str().into(),
+            samples: result.samples,
+            min_ns: result.stats.min.as_nanos(),
+            mean_ns: result.stats.mean.as_nanos(),
+            median_ns: result.stats.median.as_nanos(),
+            p95_ns: result.stats.p95.as_nanos(),
+            max_ns: result.stats.max.as_nanos(),
+            std_dev_ns: result.stats.std_dev.as_nanos(),
+            outliers: result.stats.outliers,
+        }
+    }
+}
+
+fn status(result: &PartResult) -> &'static str {
+    if let Some(failure) = &result.failure {
+        failure.as_str()
+    } else if result.answer.is_some() || result.is_parse() {
+        "success"
+    } else {
+        "failure"
+    }
+}
+
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[must_use]
+pub fn to_json(results: &[PartResult]) -> String {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+    serde_json::to_string(&records).expect("records only contain strings and numbers")
+}
+
+/// Reads the JSON output of [`to_json`].
+pub fn from_json(json: &str) -> Result<Vec<Record>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+#[must_use]
+pub fn to_csv(results: &[PartResult]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+
+    for record in results.iter().map(Record::from) {
+        lines.push(format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            record.answer.as_deref().map(escape_csv).unwrap_or_default(),
+            record.status,
+            record.verdict,
+            record.samples,
+            record.min_ns,
+            record.mean_ns,
+            record.median_ns,
+            record.p95_ns,
+            record.max_ns,
+            record.std_dev_ns,
+            record.outliers,
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Print results in a machine-readable format. Text results are printed by the runner instead.
+pub fn print(format: Format, results: &[PartResult]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(results)),
+        Format::Csv => println!("{}", to_csv(results)),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{from_json, to_csv, to_json};
+    use crate::template::answers::Verdict;
+    use crate::template::runner::{DurationStats, Failure, PartResult};
+    use crate::{day, year, PuzzleId};
+
+    fn get_mock_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                part: 1,
+                answer: Some("142".into()),
+                verdict: Verdict::Correct,
+                samples: 3,
+                stats: DurationStats {
+                    min: Duration::from_nanos(10),
+                    mean: Duration::from_nanos(20),
+                    median: Duration::from_nanos(15),
+                    p95: Duration::from_nanos(30),
+                    max: Duration::from_nanos(35),
+                    std_dev: Duration::from_nanos(5),
+                    outliers: 1,
+                },
+                failure: None,
+            },
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                part: 2,
+                answer: Some("a,\"b\"\nc".into()),
+                verdict: Verdict::Unknown,
+                samples: 1,
+                stats: DurationStats::default(),
+                failure: None,
+            },
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                part: 1,
+                answer: None,
+                verdict: Verdict::Incorrect {
+                    expected: "8".into(),
+                },
+                samples: 1,
+                stats: DurationStats::default(),
+                failure: None,
+            },
+            PartResult {
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                part: 2,
+                answer: None,
+                verdict: Verdict::Unknown,
+                samples: 0,
+                stats: DurationStats::default(),
+                failure: Some(Failure::Panic("oops".into())),
+            },
+        ]
+    }
+
+    #[test]
+    fn format_json() {
+        let expected = [
+            r#"[{"year":2023,"day":1,"part":1,"answer":"142","status":"success","verdict":"correct","samples":3,"min_ns":10,"mean_ns":20,"median_ns":15,"p95_ns":30,"max_ns":35,"std_dev_ns":5,"outliers":1},"#,
+            r#"{"year":2023,"day":1,"part":2,"answer":"a,\"b\"\nc","status":"success","verdict":"unknown","samples":1,"min_ns":0,"mean_ns":0,"median_ns":0,"p95_ns":0,"max_ns":0,"std_dev_ns":0,"outliers":0},"#,
+            r#"{"year":2023,"day":2,"part":1,"answer":null,"status":"failure","verdict":"incorrect","samples":1,"min_ns":0,"mean_ns":0,"median_ns":0,"p95_ns":0,"max_ns":0,"std_dev_ns":0,"outliers":0},"#,
+            r#"{"year":2023,"day":2,"part":2,"answer":null,"status":"panic","verdict":"unknown","samples":0,"min_ns":0,"mean_ns":0,"median_ns":0,"p95_ns":0,"max_ns":0,"std_dev_ns":0,"outliers":0}]"#,
+        ]
+        .join("");
+        assert_eq!(to_json(&get_mock_results()), expected);
+    }
+
+    #[test]
+    fn parse_json() {
+        let records = from_json(&to_json(&get_mock_results())).unwrap();
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].mean_ns, 20);
+        assert_eq!(records[1].answer.as_deref(), Some("a,\"b\"\nc"));
+        assert_eq!(records[3].status, "panic");
+    }
+
+    #[test]
+    fn format_csv() {
+        let expected = [
+            "year,day,part,answer,status,verdict,samples,min_ns,mean_ns,median_ns,p95_ns,max_ns,std_dev_ns,outliers",
+            "2023,1,1,142,success,correct,3,10,20,15,30,35,5,1",
+            "2023,1,2,\"a,\"\"b\"\"\nc\",success,unknown,1,0,0,0,0,0,0,0",
+            "2023,2,1,,failure,incorrect,1,0,0,0,0,0,0,0",
+            "2023,2,2,,panic,unknown,0,0,0,0,0,0,0,0",
+        ]
+        .join("\n");
+        assert_eq!(to_csv(&get_mock_results()), expected);
+    }
+}
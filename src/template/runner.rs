@@ -1,67 +1,674 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::answer::{ocr, Answer, ToAnswer};
+use crate::template::answers::{Answers, Verdict};
+use crate::template::aoc_client::Submission;
+use crate::template::inputs;
+use crate::template::submissions;
+use crate::template::vault;
+use crate::template::{
+    config, examples, get_data_path, report, report::Format, Error as TemplateError, ANSI_ITALIC,
+    ANSI_RESET,
+};
+use crate::PuzzleId;
+use std::cell::RefCell;
+use std::error::Error;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, IsTerminal, Write};
+use std::str::FromStr;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs};
+use std::{panic, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// A solution registered through the [`solution!`](crate::solution) macro.
+/// This lets `cargo all` call every solution in-process instead of invoking its binary.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run_parts: SolutionParts,
+}
+
+/// Runs the parse phase (if the solution has one) and both parts of a [`Solution`] against an input.
+pub type SolutionParts = fn(&str, &RunOptions) -> Vec<PartResult>;
+
+/// The part number that the parse phase of a solution is reported as.
+pub const PARSE_PART: u8 = 0;
+
+impl Solution {
+    /// Runs the solution against an input.
+    pub fn run(&self, input: &str, options: &RunOptions) -> Vec<PartResult> {
+        (self.run_parts)(input, options)
+    }
+}
+
+/// Options that control how a solution part is run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of executing it once.
+    pub is_timed: bool,
+    /// The part that should be submitted once the solution has run.
+    pub submit: Option<Submit>,
+    /// How results are printed. Only [`Format::Text`] prints while running.
+    pub format: Format,
+    pub bench: BenchOptions,
+    /// Do not compare answers against the recorded answers, e.g. when running against an input other than the puzzle input.
+    pub skip_verify: bool,
+}
+
+/// Options that control how a part is benched when running with `--time`.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// How long the part runs before samples are taken.
+    pub warmup: Duration,
+    /// How long samples are taken for, within the bounds of [`MIN_SAMPLES`] and [`MAX_SAMPLES`].
+    pub budget: Duration,
+}
+
+pub const MIN_SAMPLES: usize = 10;
+pub const MAX_SAMPLES: usize = 10000;
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// What `--submit` submits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Submit {
+    /// Submit the answer of one part.
+    Part(u8),
+    /// Submit the answer of the last part with a real answer, after confirming it.
+    Auto,
+}
+
+impl FromStr for Submit {
+    type Err = SubmitFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Submit::Auto),
+            "1" => Ok(Submit::Part(1)),
+            "2" => Ok(Submit::Part(2)),
+            _ => Err(SubmitFromStrError),
+        }
+    }
+}
+
+impl Display for Submit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submit::Part(part) => write!(f, "{part}"),
+            Submit::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Submit`].
+#[derive(Debug)]
+pub struct SubmitFromStrError;
+
+impl Error for SubmitFromStrError {}
+
+impl Display for SubmitFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `1`, `2` or `auto`")
+    }
+}
+
+/// Reads the value following a flag. A flag that is present with an invalid value is an error.
+fn arg_value<T: FromStr>(
+    args: &[String],
+    flag: &str,
+    example: &str,
+) -> Result<Option<T>, TemplateError> {
+    let Some(index) = args.iter().position(|x| x == flag) else {
+        return Ok(None);
+    };
+
+    match args.get(index + 1).and_then(|x| x.parse().ok()) {
+        Some(value) => Ok(Some(value)),
+        None => Err(TemplateError::Failed(format!(
+            "unexpected command-line input. Format: cargo solve 1 {flag} {example}"
+        ))),
+    }
+}
+
+impl RunOptions {
+    /// Reads the options passed to a solution binary, see [`RunOptions::to_args`].
+    pub fn from_args() -> Result<Self, TemplateError> {
+        let args: Vec<String> = env::args().collect();
+        let defaults = BenchOptions::default();
+
+        Ok(Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            submit: arg_value(&args, "--submit", "1")?,
+            format: arg_value(&args, "--format", "json")?.unwrap_or_default(),
+            bench: BenchOptions {
+                warmup: arg_value(&args, "--warmup", "100")?
+                    .map_or(defaults.warmup, Duration::from_millis),
+                budget: arg_value(&args, "--budget", "1000")?
+                    .map_or(defaults.budget, Duration::from_millis),
+            },
+            skip_verify: false,
+        })
+    }
+
+    /// The arguments that pass these options on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(submit) = self.submit {
+            args.push("--submit".to_string());
+            args.push(submit.to_string());
+        }
+
+        if self.is_timed {
+            args.push("--time".to_string());
+            args.push("--warmup".to_string());
+            args.push(self.bench.warmup.as_millis().to_string());
+            args.push("--budget".to_string());
+            args.push(self.bench.budget.as_millis().to_string());
+        }
+
+        if self.format != Format::Text {
+            args.push("--format".to_string());
+            args.push(self.format.to_string());
+        }
+
+        args
+    }
+
+    fn is_text(&self) -> bool {
+        self.format == Format::Text
+    }
+}
+
+/// The input a solution binary runs against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/2023/inputs/05.txt`.
+    #[default]
+    Puzzle,
+    /// The example input, e.g. `data/2023/examples/05.txt`.
+    Example,
+    /// Any other input file.
+    File(String),
+    /// The standard input.
+    Stdin,
+    /// Every named example, see [`examples`](crate::template::examples).
+    Examples,
+}
+
+impl InputSource {
+    /// Reads the input source passed to a solution binary, see [`InputSource::to_args`].
+    pub fn from_args() -> Result<Self, TemplateError> {
+        let args: Vec<String> = env::args().collect();
+
+        let source = if let Some(path) = arg_value(&args, "--input", "path/to/input.txt")? {
+            InputSource::File(path)
+        } else if args.iter().any(|x| x == "--example") {
+            InputSource::Example
+        } else if args.iter().any(|x| x == "--stdin") {
+            InputSource::Stdin
+        } else if args.iter().any(|x| x == "--examples") {
+            InputSource::Examples
+        } else {
+            InputSource::Puzzle
+        };
+
+        Ok(source)
+    }
+
+    /// The arguments that pass this input source on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example => vec!["--example".to_string()],
+            InputSource::File(path) => vec!["--input".to_string(), path.clone()],
+            InputSource::Stdin => vec!["--stdin".to_string()],
+            InputSource::Examples => vec!["--examples".to_string()],
+        }
+    }
+
+    /// Returns the path of the input, or [`None`] for the standard input.
+    /// For named examples, this is the directory that contains them.
+    #[must_use]
+    pub fn path(&self, puzzle: PuzzleId) -> Option<String> {
+        match self {
+            InputSource::Puzzle => Some(get_data_path("inputs", puzzle, "txt")),
+            InputSource::Example => Some(get_data_path("examples", puzzle, "txt")),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Examples => Some(examples::get_examples_dir(puzzle)),
+        }
+    }
+
+    /// Reads the input. Named examples are read by [`examples::run`] instead.
+    ///
+    /// The puzzle input is checked for problems first, see [`inputs::verify`]. Problems that keep the
+    /// solution from running are returned as an error, the others are printed as warnings.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, TemplateError> {
+        let path = self.path(puzzle);
+
+        let result = match &path {
+            // locked puzzle inputs are decrypted, see [`vault`].
+            Some(_) if *self == InputSource::Puzzle => vault::read_input(puzzle),
+            Some(path) => fs::read_to_string(path),
+            None => io::read_to_string(io::stdin()),
+        };
+
+        let input = result.map_err(|e| {
+            let name = path.unwrap_or_else(|| "stdin".into());
+            TemplateError::Failed(format!("could not read input from {name}: {e}"))
+        })?;
+
+        if *self == InputSource::Puzzle {
+            let problems = inputs::verify(puzzle, &input);
+
+            if let Some(problem) = problems.iter().find(|problem| problem.is_fatal()) {
+                return Err(TemplateError::Input(puzzle, *problem));
+            }
+
+            inputs::warn(puzzle, &problems);
+        }
+
+        Ok(input)
+    }
+}
+
+/// The outcome of running one part of a solution.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub puzzle: PuzzleId,
+    /// The part, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub verdict: Verdict,
+    pub samples: u128,
+    pub stats: DurationStats,
+    /// Set if the part did not finish, see [`all`](crate::template::commands::all).
+    pub failure: Option<Failure>,
+}
+
+/// Why a part did not finish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part panicked, with the message and location of the panic.
+    Panic(String),
+    /// The part or its day ran longer than the timeout.
+    Timeout(Duration),
+}
+
+impl Failure {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Failure::Panic(_) => "panic",
+            Failure::Timeout(_) => "timeout",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+            Failure::Timeout(timeout) => write!(f, "timed out after {timeout:?}"),
+        }
+    }
+}
+
+/// Progress of a solution that runs on another thread, see [`report_parts_to`].
+#[derive(Debug)]
+pub enum PartEvent {
+    Started(u8),
+    Finished(Box<PartResult>),
+}
+
+/// Lets the thread that waits for the parts of another thread give up on them, e.g. after a timeout.
+#[derive(Debug, Clone, Default)]
+pub struct Abandon(Arc<Mutex<bool>>);
+
+impl Abandon {
+    /// Once this returns, the abandoned parts print nothing more.
+    pub fn abandon(&self) {
+        *self.lock() = true;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, bool> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+thread_local! {
+    static PART_EVENTS: RefCell<Option<(Sender<PartEvent>, Abandon)>> = const { RefCell::new(None) };
+}
+
+/// Sends the parts that run on the current thread to `sender` as they start and finish.
+/// Once they are abandoned or the receiver is dropped, the parts stop printing and the thread unwinds
+/// when the next part starts or finishes.
+pub fn report_parts_to(sender: Sender<PartEvent>, abandon: Abandon) {
+    PART_EVENTS.with(|events| *events.borrow_mut() = Some((sender, abandon)));
+}
+
+/// Prints the live output of a part, unless the part was abandoned, see [`report_parts_to`].
+fn print_live(is_text: bool, print: impl FnOnce()) {
+    if !is_text {
+        return;
+    }
+
+    PART_EVENTS.with(|events| match &*events.borrow() {
+        Some((_, abandon)) => {
+            let is_abandoned = abandon.lock();
+            if !*is_abandoned {
+                print();
+            }
+        }
+        None => print(),
+    });
+}
+
+fn report_part(event: PartEvent) {
+    let is_abandoned = PART_EVENTS.with(|events| {
+        events
+            .borrow()
+            .as_ref()
+            .is_some_and(|(sender, abandon)| *abandon.lock() || sender.send(event).is_err())
+    });
+
+    if is_abandoned && !thread::panicking() {
+        // unwinding without a panic message stops a thread whose results nobody waits for.
+        panic::resume_unwind(Box::new(()));
+    }
+}
+
+impl PartResult {
+    #[must_use]
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    /// Whether the part was benched and either is the parse phase or produced an answer.
+    /// Only these results are meaningful benchmarks.
+    #[must_use]
+    pub fn is_benched(&self) -> bool {
+        self.samples > 1 && (self.is_parse() || self.answer.is_some())
+    }
+}
+
+/// Returns a label for a part, e.g. `part 1` or `parse`.
+#[must_use]
+pub fn format_part(part: u8) -> String {
+    if part == PARSE_PART {
+        "parse".into()
+    } else {
+        format!("part {part}")
+    }
+}
+
+/// Summary of the execution times of a part. All values are equal if it was executed once.
+///
+/// Samples outside of Tukey's fences (1.5 times the interquartile range below the first or
+/// above the third quartile) count as outliers and are excluded from the mean and standard deviation.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DurationStats {
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub outliers: usize,
+}
+
+impl DurationStats {
+    /// Computes the statistics of a non-empty list of samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let q1 = percentile(samples, 25);
+        let q3 = percentile(samples, 75);
+        let fence = (q3 - q1) * 3 / 2;
+        let (lower, upper) = (q1.saturating_sub(fence), q3 + fence);
+
+        let inliers: Vec<Duration> = samples
+            .iter()
+            .copied()
+            .filter(|x| *x >= lower && *x <= upper)
+            .collect();
+
+        let mean = average_duration(&inliers);
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = inliers
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / inliers.len() as f64;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Self {
+            min: samples[0],
+            mean: Duration::from_nanos(mean as u64),
+            median: percentile(samples, 50),
+            p95: percentile(samples, 95),
+            max: samples[samples.len() - 1],
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            outliers: samples.len() - inliers.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Median => self.median,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+/// The statistic that summarizes a benched part, e.g. in the README table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Statistic {
+    #[default]
+    Mean,
+    Median,
+}
+
+impl FromStr for Statistic {
+    type Err = StatisticFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Statistic::Mean),
+            "median" => Ok(Statistic::Median),
+            _ => Err(StatisticFromStrError),
+        }
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Statistic::Mean => "mean",
+            Statistic::Median => "median",
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`Statistic`].
+#[derive(Debug)]
+pub struct StatisticFromStrError;
+
+impl Error for StatisticFromStrError {}
+
+impl Display for StatisticFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `mean` or `median`")
+    }
+}
+
+pub fn run_part<I: Clone, T: ToAnswer>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    report_part(PartEvent::Started(part));
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats, samples) = run_timed(func, input, options, |result| {
+        print_live(options.is_text(), || {
+            print_result(&result.as_ref().map(ToAnswer::to_answer), &part_str, "");
+        });
+    });
+
+    let result = result.as_ref().map(ToAnswer::to_answer);
+    let answer = result.as_ref().map(Answer::submission);
+
+    let verdict = match Answers::read(puzzle) {
+        _ if options.skip_verify => Verdict::Unknown,
+        Ok(answers) => answers.verify(part, answer.as_deref()),
+        Err(e) => {
+            eprintln!("Could not read recorded answers: {e:?}");
+            Verdict::Unknown
+        }
+    };
+
+    print_live(options.is_text(), || {
+        let suffix = format!(
+            "{}{}",
+            format_verdict(&verdict),
+            format_duration(&stats, samples)
+        );
+        print_result(&result, &part_str, &suffix);
+    });
+
+    let result = PartResult {
+        puzzle,
+        part,
+        answer,
+        verdict,
+        samples,
+        stats,
+        failure: None,
+    };
+
+    report_part(PartEvent::Finished(Box::new(result.clone())));
+    result
+}
+
+/// Run the parse phase of a solution. The parsed input is returned for the parts to share.
+pub fn run_parse<T>(
+    func: impl Fn(&str) -> T,
+    input: &str,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> (T, PartResult) {
+    report_part(PartEvent::Started(PARSE_PART));
+
+    print_live(options.is_text(), || {
+        print!("Parse");
+        let _ = stdout().flush();
+    });
+
+    let (parsed, stats, samples) = run_timed(func, input, options, |_| {});
+
+    print_live(options.is_text(), || {
+        print!("\r");
+        println!("Parse:{}", format_duration(&stats, samples));
+    });
+
+    let result = PartResult {
+        puzzle,
+        part: PARSE_PART,
+        answer: None,
+        verdict: Verdict::Unknown,
+        samples,
+        stats,
+        failure: None,
+    };
+
+    report_part(PartEvent::Finished(Box::new(result.clone())));
+    (parsed, result)
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Prints the result of a part or the parse phase the way it is printed once it finished running.
+/// Used to print results that were collected without [`Format::Text`].
+pub fn print_part(result: &PartResult) {
+    let duration = format_duration(&result.stats, result.samples);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if result.is_parse() {
+        println!("Parse:{duration}");
+    } else {
+        let suffix = format!("{}{duration}", format_verdict(&result.verdict));
+        let answer = result.answer.as_deref().map(ToAnswer::to_answer);
+        print_result(&answer, &format!("Part {}", result.part), &suffix);
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether `--time` was passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched (after a warmup, for the time budget or at least 10 samples, whatever takes longer.)
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, DurationStats, u128) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let run = if options.is_timed {
+        bench(func, input, &options.bench, options.is_text())
     } else {
-        (base_time, 1)
+        (DurationStats::from_samples(&mut [base_time]), 1)
     };
 
     (result, run.0, run.1)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    options: &BenchOptions,
+    show_progress: bool,
+) -> (DurationStats, u128) {
+    print_live(show_progress, || {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    });
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let warmup = Instant::now();
+    while warmup.elapsed() < options.warmup {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let budget = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < MIN_SAMPLES
+        || (timers.len() < MAX_SAMPLES && budget.elapsed() < options.budget)
+    {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,11 +676,8 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let samples = timers.len() as u128;
+    (DurationStats::from_samples(&mut timers), samples)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -84,36 +688,53 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+fn format_duration(stats: &DurationStats, samples: u128) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
+        format!(" ({:.1?})", stats.mean)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?}, median {:.1?}, p95 {:.1?} @ {samples} samples, {} outliers)",
+            stats.mean, stats.std_dev, stats.median, stats.p95, stats.outliers
+        )
+    }
+}
+
+fn format_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => " ✔".into(),
+        Verdict::Incorrect { expected } if !expected.contains('\n') => {
+            format!(" ✘ (expected {expected})")
+        }
+        Verdict::Incorrect { .. } => " ✘".into(),
+        Verdict::Unknown => String::new(),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
+        Some(Answer::Drawing(drawing)) => {
+            let letters = match ocr(drawing) {
+                Some(letters) => format!("{ANSI_BOLD}{letters}{ANSI_RESET} "),
+                None => String::new(),
+            };
+            let str = format!("{part}: {letters}▼ {duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
+                println!("{}", drawing.trim_end_matches('\n'));
+            }
+        }
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+            let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+            if is_intermediate_result {
+                print!("{str}");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                print!("\r");
+                println!("{str}");
             }
         }
         None => {
@@ -127,41 +748,212 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Whether an answer is obviously not a real answer, e.g. the `0` of an unfinished part.
+#[must_use]
+pub fn is_placeholder(answer: &str) -> bool {
+    let answer = answer.trim();
+    answer.is_empty() || answer == "0" || answer.contains('\n')
+}
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+/// Picks the part to submit with `--submit auto`: part 2 once it has an answer, part 1 otherwise.
+fn pick_auto(results: &[PartResult]) -> Option<&PartResult> {
+    let answered = |part: u8| {
+        results
+            .iter()
+            .find(|r| r.part == part)
+            .filter(|r| r.answer.is_some())
+    };
+
+    let result = answered(2).or_else(|| answered(1))?;
+
+    match &result.answer {
+        Some(answer) if !is_placeholder(answer) => Some(result),
+        _ => None,
     }
+}
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+/// Asks the user to confirm on the terminal, defaulting to no.
+fn confirm(prompt: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        eprintln!("Can not ask for confirmation without an interactive terminal.");
+        return false;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    print!("{prompt} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && matches!(line.trim(), "y" | "Y" | "yes")
+}
+
+/// Submits the part passed to `--submit` if the solution produced an answer for it. Returns [`None`]
+/// if nothing was submitted.
+pub fn submit_results(
+    results: &[PartResult],
+    options: &RunOptions,
+) -> Result<Option<Submission>, submissions::Error> {
+    let Some(submit) = options.submit else {
+        return Ok(None);
+    };
+
+    let result = match submit {
+        Submit::Part(part) => {
+            let Some(result) = results
+                .iter()
+                .find(|r| r.part == part && r.answer.is_some())
+            else {
+                return Ok(None);
+            };
+
+            result
+        }
+        Submit::Auto => {
+            let Some(result) = pick_auto(results) else {
+                println!("Nothing to submit, there is no answer that looks like a real one.");
+                return Ok(None);
+            };
+
+            let answer = result.answer.as_deref().unwrap_or_default();
+            let prompt = format!("Submit {answer} for {}?", format_part(result.part));
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+            if !confirm(&prompt) {
+                println!("Not submitting.");
+                return Ok(None);
+            }
+
+            result
+        }
     };
 
-    if part_submit != part {
-        return None;
+    println!("Submitting result...");
+    let answer = result.answer.as_deref().unwrap_or_default();
+    submissions::submit(result.puzzle, result.part, answer).map(Some)
+}
+
+/// Runs a solution binary with the input and options passed on the command line, see
+/// [`solution!`](crate::solution).
+pub fn run_from_args(solution: &Solution) -> Result<(), TemplateError> {
+    config::get()?;
+    let source = InputSource::from_args()?;
+    // recorded answers only apply to the puzzle input.
+    let options = RunOptions {
+        skip_verify: source != InputSource::Puzzle,
+        ..RunOptions::from_args()?
+    };
+
+    if source == InputSource::Examples {
+        return examples::run(solution, &options);
+    }
+
+    let input = source.read(solution.puzzle)?;
+    let results = solution.run(&input, &options);
+    let submission = submit_results(&results, &options);
+    report::print(options.format, &results);
+
+    submission?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        arg_value, is_placeholder, pick_auto, DurationStats, PartResult, Submit, PARSE_PART,
+    };
+    use crate::template::answers::Verdict;
+    use crate::{day, year, PuzzleId};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn stats_of_single_sample() {
+        let stats = DurationStats::from_samples(&mut nanos(&[42]));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn stats_reject_outliers() {
+        let mut samples = nanos(&[10, 12, 11, 13, 9, 10, 11, 12, 10, 1000]);
+        let stats = DurationStats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_nanos(9));
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+        assert_eq!(stats.median, Duration::from_nanos(11));
+        assert_eq!(stats.p95, Duration::from_nanos(1000));
+        assert_eq!(stats.outliers, 1);
+        // mean and std. deviation only consider the remaining samples.
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    fn part_result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            puzzle: PuzzleId::new(year!(2023), day!(1)),
+            part,
+            answer: answer.map(Into::into),
+            verdict: Verdict::Unknown,
+            samples: 1,
+            stats: DurationStats::default(),
+            failure: None,
+        }
+    }
+
+    #[test]
+    fn parses_submit() {
+        assert_eq!("auto".parse::<Submit>().unwrap(), Submit::Auto);
+        assert_eq!("2".parse::<Submit>().unwrap(), Submit::Part(2));
+        assert!("3".parse::<Submit>().is_err());
+    }
+
+    #[test]
+    fn detects_placeholders() {
+        assert!(is_placeholder("0"));
+        assert!(is_placeholder(""));
+        assert!(is_placeholder("#..\n.#."));
+        assert!(!is_placeholder("142"));
+    }
+
+    #[test]
+    fn auto_submits_last_answered_part() {
+        let results = [
+            part_result(PARSE_PART, None),
+            part_result(1, Some("142")),
+            part_result(2, None),
+        ];
+        assert_eq!(pick_auto(&results).map(|r| r.part), Some(1));
+
+        let results = [part_result(1, Some("142")), part_result(2, Some("281"))];
+        assert_eq!(pick_auto(&results).map(|r| r.part), Some(2));
+
+        let results = [part_result(1, Some("142")), part_result(2, Some("0"))];
+        assert!(pick_auto(&results).is_none());
+
+        let results = [part_result(1, None), part_result(2, None)];
+        assert!(pick_auto(&results).is_none());
+    }
+
+    #[test]
+    fn reads_arg_values() {
+        let args: Vec<String> = ["2023-01", "--submit", "auto", "--warmup", "soon"]
+            .map(String::from)
+            .to_vec();
+
+        assert!(matches!(
+            arg_value::<Submit>(&args, "--submit", "1"),
+            Ok(Some(Submit::Auto))
+        ));
+        assert!(matches!(
+            arg_value::<u64>(&args, "--budget", "1000"),
+            Ok(None)
+        ));
+        assert!(arg_value::<u64>(&args, "--warmup", "100").is_err());
+    }
 }
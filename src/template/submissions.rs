@@ -0,0 +1,470 @@
+/// Module that keeps a log of every submitted answer and guards against pointless submissions.
+/// The log of a puzzle lives in `data/<year>/submissions/<day>.toml` and records the server's
+/// verdict of every attempt. Before submitting, the log is checked so that known-wrong answers are
+/// never resubmitted, answers outside of known bounds are flagged and the cooldown is respected.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::aoc_client::{self, AocClientError, Hint, Submission};
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+    Client(AocClientError),
+    Refused(Refusal),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<AocClientError> for Error {
+    fn from(e: AocClientError) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission log: {e}"),
+            Error::IO(e) => write!(f, "could not access submission log: {e}"),
+            Error::Client(e) => write!(f, "{e}"),
+            Error::Refused(refusal) => write!(f, "refusing to submit, {refusal}"),
+        }
+    }
+}
+
+/// The server's verdict on an attempt, as recorded in the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    Cooldown,
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    fn from_submission(submission: &Submission) -> Self {
+        match submission {
+            Submission::Correct => Outcome::Correct,
+            Submission::Incorrect { hint: None, .. } => Outcome::Incorrect,
+            Submission::Incorrect {
+                hint: Some(Hint::TooHigh),
+                ..
+            } => Outcome::TooHigh,
+            Submission::Incorrect {
+                hint: Some(Hint::TooLow),
+                ..
+            } => Outcome::TooLow,
+            Submission::Cooldown { .. } => Outcome::Cooldown,
+            Submission::WrongLevel => Outcome::WrongLevel,
+            Submission::Unknown => Outcome::Unknown,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Cooldown => "cooldown",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        [
+            Outcome::Correct,
+            Outcome::Incorrect,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Cooldown,
+            Outcome::WrongLevel,
+            Outcome::Unknown,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.as_str() == s)
+    }
+
+    /// Whether the server judged the answer to be wrong.
+    #[must_use]
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// When the server accepts the next submission, in seconds since the unix epoch.
+    pub retry_at: Option<u64>,
+}
+
+/// A reason not to submit an answer at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { part: u8, answer: String },
+    AlreadyRejected { answer: String, outcome: Outcome },
+    Cooldown { wait: Duration },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { part, answer } => {
+                write!(f, "part {part} was already solved with {answer}.")
+            }
+            Refusal::AlreadyRejected { answer, outcome } => match outcome {
+                Outcome::TooHigh => write!(f, "{answer} was already rejected as too high."),
+                Outcome::TooLow => write!(f, "{answer} was already rejected as too low."),
+                _ => write!(f, "{answer} was already rejected."),
+            },
+            Refusal::Cooldown { wait } => write!(
+                f,
+                "the cooldown has {}s left, try again later.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// A reason to doubt an answer, which is submitted anyway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    AboveTooHigh { bound: String },
+    BelowTooLow { bound: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::AboveTooHigh { bound } => {
+                write!(
+                    f,
+                    "the answer is not lower than {bound}, which was too high."
+                )
+            }
+            Warning::BelowTooLow { bound } => {
+                write!(
+                    f,
+                    "the answer is not higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    pub attempts: Vec<Attempt>,
+}
+
+#[must_use]
+pub fn get_log_path(puzzle: PuzzleId) -> String {
+    get_data_path("submissions", puzzle, "toml")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl SubmissionLog {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let table: toml::Table = s.parse().map_err(|e| Error::Parser(format!("{e}")))?;
+
+        let Some(attempts) = table.get("attempt") else {
+            return Ok(SubmissionLog::default());
+        };
+
+        let malformed = || Error::Parser("malformed `[[attempt]]` entry.".into());
+
+        let attempts = attempts
+            .as_array()
+            .ok_or_else(malformed)?
+            .iter()
+            .map(|attempt| {
+                let integer = |key: &str| attempt.get(key).and_then(toml::Value::as_integer);
+                let string = |key: &str| attempt.get(key).and_then(toml::Value::as_str);
+
+                Ok(Attempt {
+                    part: integer("part")
+                        .and_then(|x| x.try_into().ok())
+                        .ok_or_else(malformed)?,
+                    answer: string("answer").ok_or_else(malformed)?.to_string(),
+                    outcome: string("outcome")
+                        .and_then(Outcome::parse)
+                        .ok_or_else(malformed)?,
+                    timestamp: integer("timestamp")
+                        .and_then(|x| x.try_into().ok())
+                        .ok_or_else(malformed)?,
+                    retry_at: integer("retry_at").and_then(|x| x.try_into().ok()),
+                })
+            })
+            .collect::<Result<Vec<Attempt>, Error>>()?;
+
+        Ok(SubmissionLog { attempts })
+    }
+
+    /// Reads the log of a puzzle. A missing log means nothing was submitted yet.
+    pub fn read(puzzle: PuzzleId) -> Result<Self, Error> {
+        match fs::read_to_string(get_log_path(puzzle)) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        let attempts = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let mut table = toml::Table::new();
+                table.insert("part".into(), i64::from(attempt.part).into());
+                table.insert("answer".into(), attempt.answer.clone().into());
+                table.insert("outcome".into(), attempt.outcome.as_str().into());
+                #[allow(clippy::cast_possible_wrap)]
+                table.insert("timestamp".into(), (attempt.timestamp as i64).into());
+                if let Some(retry_at) = attempt.retry_at {
+                    #[allow(clippy::cast_possible_wrap)]
+                    table.insert("retry_at".into(), (retry_at as i64).into());
+                }
+                toml::Value::Table(table)
+            })
+            .collect::<Vec<_>>();
+
+        let mut root = toml::Table::new();
+        root.insert("attempt".into(), toml::Value::Array(attempts));
+        root.to_string()
+    }
+
+    pub fn write(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = get_log_path(puzzle);
+
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, self.to_toml())?;
+        Ok(())
+    }
+
+    /// Checks an answer against the log before submitting it at time `now`.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<Vec<Warning>, Refusal> {
+        let attempts = || self.attempts.iter().filter(|a| a.part == part);
+
+        if let Some(solved) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved {
+                part,
+                answer: solved.answer.clone(),
+            });
+        }
+
+        if let Some(rejected) = attempts().find(|a| a.outcome.is_rejected() && a.answer == answer) {
+            return Err(Refusal::AlreadyRejected {
+                answer: answer.to_string(),
+                outcome: rejected.outcome,
+            });
+        }
+
+        // the cooldown applies to all parts.
+        if let Some(retry_at) = self.attempts.iter().filter_map(|a| a.retry_at).max() {
+            if retry_at > now {
+                return Err(Refusal::Cooldown {
+                    wait: Duration::from_secs(retry_at - now),
+                });
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(vec![]);
+        };
+
+        let bound = |outcome: Outcome| {
+            attempts()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| Some((a.answer.parse::<i128>().ok()?, &a.answer)))
+        };
+
+        let mut warnings = vec![];
+
+        if let Some((lowest, answer)) = bound(Outcome::TooHigh).min() {
+            if value >= lowest {
+                warnings.push(Warning::AboveTooHigh {
+                    bound: answer.clone(),
+                });
+            }
+        }
+
+        if let Some((highest, answer)) = bound(Outcome::TooLow).max() {
+            if value <= highest {
+                warnings.push(Warning::BelowTooLow {
+                    bound: answer.clone(),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    /// Records the server's verdict on an answer submitted at time `now`.
+    pub fn record(&mut self, part: u8, answer: &str, submission: &Submission, now: u64) {
+        let wait = match submission {
+            Submission::Incorrect { wait, .. } | Submission::Cooldown { wait } => *wait,
+            _ => None,
+        };
+
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.to_string(),
+            outcome: Outcome::from_submission(submission),
+            timestamp: now,
+            retry_at: wait.map(|wait| now + wait.as_secs()),
+        });
+    }
+}
+
+/// Submits an answer unless the log shows that it is pointless, and records the server's verdict.
+pub fn submit(puzzle: PuzzleId, part: u8, answer: &str) -> Result<Submission, Error> {
+    let mut log = SubmissionLog::read(puzzle)?;
+
+    let warnings = log.check(part, answer, now()).map_err(Error::Refused)?;
+
+    for warning in warnings {
+        eprintln!("Warning: {warning}");
+    }
+
+    let submission = aoc_client::submit(puzzle, part, answer)?;
+
+    log.record(part, answer, &submission, now());
+    log.write(puzzle)?;
+
+    Ok(submission)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Outcome, Refusal, SubmissionLog, Warning};
+    use crate::template::aoc_client::{Hint, Submission};
+
+    fn incorrect(hint: Option<Hint>) -> Submission {
+        Submission::Incorrect {
+            hint,
+            wait: Some(Duration::from_secs(60)),
+        }
+    }
+
+    #[test]
+    fn roundtrips_log() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "35", &incorrect(Some(Hint::TooLow)), 100);
+        log.record(1, "a\nb", &Submission::Correct, 200);
+
+        let parsed = SubmissionLog::parse(&log.to_toml()).unwrap();
+        assert_eq!(parsed, log);
+        assert_eq!(parsed.attempts[0].outcome, Outcome::TooLow);
+        assert_eq!(parsed.attempts[0].retry_at, Some(160));
+        assert_eq!(parsed.attempts[1].retry_at, None);
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "35", &incorrect(None), 100);
+
+        assert_eq!(
+            log.check(1, "35", 1000),
+            Err(Refusal::AlreadyRejected {
+                answer: "35".into(),
+                outcome: Outcome::Incorrect
+            })
+        );
+        assert_eq!(log.check(2, "35", 1000), Ok(vec![]));
+        assert_eq!(log.check(1, "36", 1000), Ok(vec![]));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "35", &Submission::Correct, 100);
+
+        assert_eq!(
+            log.check(1, "36", 1000),
+            Err(Refusal::AlreadySolved {
+                part: 1,
+                answer: "35".into()
+            })
+        );
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "35", &incorrect(None), 100);
+
+        assert_eq!(
+            log.check(2, "1", 130),
+            Err(Refusal::Cooldown {
+                wait: Duration::from_secs(30)
+            })
+        );
+        assert_eq!(
+            Refusal::Cooldown {
+                wait: Duration::from_secs(30)
+            }
+            .to_string(),
+            "the cooldown has 30s left, try again later."
+        );
+        assert_eq!(log.check(2, "1", 160), Ok(vec![]));
+    }
+
+    #[test]
+    fn warns_outside_of_bounds() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "100", &incorrect(Some(Hint::TooHigh)), 0);
+        log.record(1, "500", &incorrect(Some(Hint::TooHigh)), 0);
+        log.record(1, "10", &incorrect(Some(Hint::TooLow)), 0);
+
+        assert_eq!(log.check(1, "50", 1000), Ok(vec![]));
+        assert_eq!(
+            log.check(1, "200", 1000),
+            Ok(vec![Warning::AboveTooHigh {
+                bound: "100".into()
+            }])
+        );
+        assert_eq!(
+            log.check(1, "5", 1000),
+            Ok(vec![Warning::BelowTooLow { bound: "10".into() }])
+        );
+        assert_eq!(log.check(1, "abc", 1000), Ok(vec![]));
+    }
+}
@@ -0,0 +1,305 @@
+/// Module that stores puzzle inputs encrypted at rest, so that they can be committed without
+/// publishing them.
+///
+/// Inputs are encrypted with ChaCha20-Poly1305 and a key that stays on your machine. It is read from
+/// the `AOC_VAULT_KEY` environment variable or from `~/.adventofcode.vault-key`. Encrypted inputs live
+/// in `data/<year>/vault/<key id>/<day>.enc`, so the inputs of everyone who shares a repository can
+/// be stored side by side.
+use std::fmt::{Display, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::template::{get_data_dir, get_data_path};
+use crate::{PuzzleId, Year};
+
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    KeyNotFound,
+    /// The input is only in the vault and there is no key to decrypt it.
+    Locked,
+    InvalidKey,
+    /// The encrypted input was modified or encrypted with another key.
+    Decrypt,
+    /// The plain text input differs from the encrypted one.
+    Conflict,
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::KeyNotFound => write!(
+                f,
+                "no vault key found, set `AOC_VAULT_KEY` or run `cargo vault lock` to create one."
+            ),
+            Error::Locked => write!(
+                f,
+                "the input is locked in the vault and no vault key was found, set `AOC_VAULT_KEY` or save the key to `~/.adventofcode.vault-key`."
+            ),
+            Error::InvalidKey => write!(f, "the vault key should be 64 hexadecimal characters."),
+            Error::Decrypt => write!(
+                f,
+                "could not decrypt the input, was it locked with another key?"
+            ),
+            Error::Conflict => write!(
+                f,
+                "the plain text input differs from the locked one, lock or delete it first."
+            ),
+            Error::IO(e) => write!(f, "could not read or write the vault: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The key that inputs are encrypted with.
+pub struct Key(chacha20poly1305::Key);
+
+/// Returns the path of the key file in the home directory, e.g. `~/.adventofcode.vault-key`.
+#[must_use]
+pub fn get_key_path() -> Option<PathBuf> {
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+    Some(Path::new(&home).join(".adventofcode.vault-key"))
+}
+
+impl Key {
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    /// Parses a hex-encoded key.
+    pub fn parse(hex: &str) -> Result<Self, Error> {
+        let hex = hex.trim();
+
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(Error::InvalidKey);
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| Error::InvalidKey))
+            .collect::<Result<Vec<u8>, Error>>()?;
+
+        Ok(Self(*chacha20poly1305::Key::from_slice(&bytes)))
+    }
+
+    /// Reads the key from `AOC_VAULT_KEY` or `~/.adventofcode.vault-key`.
+    pub fn read() -> Result<Self, Error> {
+        if let Ok(hex) = env::var("AOC_VAULT_KEY") {
+            return Self::parse(&hex);
+        }
+
+        let path = get_key_path().ok_or(Error::KeyNotFound)?;
+
+        match fs::read_to_string(path) {
+            Ok(hex) => Self::parse(&hex),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(Error::KeyNotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes the key to `~/.adventofcode.vault-key`, which must not exist yet.
+    pub fn write(&self) -> Result<PathBuf, Error> {
+        let path = get_key_path().ok_or(Error::KeyNotFound)?;
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        io::Write::write_all(
+            &mut options.open(&path)?,
+            format!("{}\n", self.to_hex()).as_bytes(),
+        )?;
+        Ok(path)
+    }
+
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    /// A short identifier of the key that does not reveal it, used to name its vault directory.
+    #[must_use]
+    pub fn id(&self) -> String {
+        to_hex(&Sha256::digest(self.0)[..4])
+    }
+
+    /// Encrypts an input. The random nonce is stored in front of the cipher text.
+    #[must_use]
+    pub fn encrypt(&self, input: &str) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let cipher_text = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, input.as_bytes())
+            .expect("encrypting an input in memory should not fail");
+
+        [nonce.as_slice(), &cipher_text].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String, Error> {
+        if data.len() < NONCE_LEN {
+            return Err(Error::Decrypt);
+        }
+
+        let (nonce, cipher_text) = data.split_at(NONCE_LEN);
+        let input = ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), cipher_text)
+            .map_err(|_| Error::Decrypt)?;
+
+        String::from_utf8(input).map_err(|_| Error::Decrypt)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// Returns the directory of the encrypted inputs of a year, e.g. `data/2023/vault/1a2b3c4d`.
+#[must_use]
+pub fn get_vault_dir(year: Year, key: &Key) -> String {
+    format!("{}/{year}/vault/{}", get_data_dir(), key.id())
+}
+
+/// Returns the path of an encrypted input, e.g. `data/2023/vault/1a2b3c4d/05.enc`.
+#[must_use]
+pub fn get_vault_path(puzzle: PuzzleId, key: &Key) -> String {
+    format!("{}/{}.enc", get_vault_dir(puzzle.year, key), puzzle.day)
+}
+
+/// Reads a plain text input. An empty input is the placeholder that `scaffold` creates, it does not
+/// hide a locked input.
+fn read_plain(puzzle: PuzzleId) -> io::Result<Option<String>> {
+    match fs::read_to_string(get_data_path("inputs", puzzle, "txt")) {
+        Ok(input) if input.is_empty() => Ok(None),
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Decrypts the input of a day, or returns [`None`] if it is not locked.
+pub fn read_locked(puzzle: PuzzleId, key: &Key) -> Result<Option<String>, Error> {
+    match fs::read(get_vault_path(puzzle, key)) {
+        Ok(data) => key.decrypt(&data).map(Some),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Whether the input of a day is locked with any key.
+fn is_locked(puzzle: PuzzleId) -> bool {
+    let vault_dir = format!("{}/{}/vault", get_data_dir(), puzzle.year);
+
+    fs::read_dir(vault_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| entry.path().join(format!("{}.enc", puzzle.day)).exists())
+}
+
+/// Reads the puzzle input of a day. If there is no plain text input, it is decrypted from the vault.
+pub fn read_input(puzzle: PuzzleId) -> io::Result<String> {
+    if let Some(input) = read_plain(puzzle)? {
+        return Ok(input);
+    }
+
+    let locked = match Key::read() {
+        Ok(key) => read_locked(puzzle, &key).map_err(io::Error::other)?,
+        Err(Error::KeyNotFound) if is_locked(puzzle) => {
+            return Err(io::Error::other(Error::Locked))
+        }
+        Err(Error::KeyNotFound) => None,
+        Err(e) => return Err(io::Error::other(e)),
+    };
+
+    match locked {
+        Some(input) => Ok(input),
+        None => fs::read_to_string(get_data_path("inputs", puzzle, "txt")),
+    }
+}
+
+/// Encrypts the plain text input of a day and removes it. Returns whether there was an input to lock.
+pub fn lock(puzzle: PuzzleId, key: &Key) -> Result<bool, Error> {
+    let Some(input) = read_plain(puzzle)? else {
+        return Ok(false);
+    };
+
+    let path = get_vault_path(puzzle, key);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(&path, key.encrypt(&input))?;
+    fs::remove_file(get_data_path("inputs", puzzle, "txt"))?;
+    Ok(true)
+}
+
+/// Decrypts the input of a day to a plain text file. Returns whether there was an input to unlock.
+pub fn unlock(puzzle: PuzzleId, key: &Key) -> Result<bool, Error> {
+    let Some(input) = read_locked(puzzle, key)? else {
+        return Ok(false);
+    };
+
+    match read_plain(puzzle)? {
+        Some(plain) if plain == input => Ok(false),
+        Some(_) => Err(Error::Conflict),
+        None => {
+            fs::write(get_data_path("inputs", puzzle, "txt"), input)?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, Key};
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn parses_keys() {
+        let key = Key::parse(&format!("{KEY}\n")).unwrap();
+        assert_eq!(key.to_hex(), KEY);
+        assert_eq!(key.id().len(), 8);
+
+        assert!(matches!(Key::parse("abc"), Err(Error::InvalidKey)));
+        assert!(matches!(
+            Key::parse(&KEY.replace('0', "g")),
+            Err(Error::InvalidKey)
+        ));
+    }
+
+    #[test]
+    fn encrypts_inputs() {
+        let key = Key::parse(KEY).unwrap();
+        let data = key.encrypt("1 2\n3 4\n");
+
+        assert_ne!(key.encrypt("1 2\n3 4\n"), data);
+        assert_eq!(key.decrypt(&data).unwrap(), "1 2\n3 4\n");
+
+        let other = Key::generate();
+        assert!(matches!(other.decrypt(&data), Err(Error::Decrypt)));
+
+        let mut tampered = data.clone();
+        tampered[20] ^= 1;
+        assert!(matches!(key.decrypt(&tampered), Err(Error::Decrypt)));
+        assert!(matches!(key.decrypt(&data[..4]), Err(Error::Decrypt)));
+    }
+}